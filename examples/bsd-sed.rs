@@ -72,24 +72,24 @@ impl CmdOP {
 //
 #[rustfmt::skip]
 const OPT_ARY: [Opt;12] = [
-    Opt { sho: b'H', lon: "help",    has: Arg::No, num: CmdOP::Help.to(), },
-    Opt { sho: b'V', lon: "version", has: Arg::No, num: CmdOP::Version.to(), },
     Opt { sho: b'E', lon: "", has: Arg::No,  num: CmdOP::ExtRegex.to(), },
-    Opt { sho: b'I', lon: "", has: Arg::Yes, num: CmdOP::Inplace.to(), },
+    Opt { sho: b'I', lon: "", has: Arg::MaybeNext, num: CmdOP::Inplace.to(), },
     Opt { sho: b'a', lon: "", has: Arg::No,  num: CmdOP::Await.to(), },
     Opt { sho: b'e', lon: "", has: Arg::Yes, num: CmdOP::Edit.to(), },
     Opt { sho: b'f', lon: "", has: Arg::Yes, num: CmdOP::File.to(), },
-    Opt { sho: b'i', lon: "", has: Arg::Yes, num: CmdOP::Independ.to(), },
+    Opt { sho: b'i', lon: "", has: Arg::MaybeNext, num: CmdOP::Independ.to(), },
     Opt { sho: b'l', lon: "", has: Arg::No,  num: CmdOP::Linebuf.to(), },
     Opt { sho: b'n', lon: "", has: Arg::No,  num: CmdOP::NotEach.to(), },
     Opt { sho: b'r', lon: "", has: Arg::No,  num: CmdOP::Regex.to(), },
     Opt { sho: b'u', lon: "", has: Arg::No,  num: CmdOP::Unbuffer.to(), },
+    Opt { sho: b'H', lon: "help",    has: Arg::No, num: CmdOP::Help.to(), },
+    Opt { sho: b'V', lon: "version", has: Arg::No, num: CmdOP::Version.to(), },
 ];
 
 #[rustfmt::skip]
 const OPT_ARY_SHO_IDX: [(u8,usize);10] = [
-    (b'E',2), (b'I',3), (b'a',4), (b'e',5), (b'f',6),
-    (b'i',7), (b'l',8), (b'n',9), (b'r',10), (b'u',11)
+    (b'E',0), (b'I',1), (b'a',2), (b'e',3), (b'f',4),
+    (b'i',5), (b'l',6), (b'n',7), (b'r',8), (b'u',9)
 ];
 
 //----------------------------------------------------------------------
@@ -353,4 +353,37 @@ mod example {
         );
         assert_eq!(thing, expect);
     }
+    #[test]
+    fn test_bsd_sed_4() {
+        let program = "test-bsd-sed";
+        #[rustfmt::skip]
+        let args = vec!["-I", ".bak", "-i", "-e", "s/abc/ABC/", "f1"];
+        //
+        let conf = match super::parse_cmdopts(program, args) {
+            Ok(conf) => conf,
+            Err(err) => {
+                assert_eq!(format!("{}", err), "");
+                unreachable!();
+            }
+        };
+        //
+        let thing = format!("{:?}", conf);
+        let expect = concat!(
+            "CmdOptConf {",
+            " opt_program: \"test-bsd-sed\",",
+            " flag_ext_regex: false,",
+            " flag_a_wait: false,",
+            " flag_edit: \"s/abc/ABC/\",",
+            " flag_file: \"\",",
+            " flag_in_place: \".bak\",",
+            " flag_independ: \"\",",
+            " flag_linu_buf: false,",
+            " flag_not_each: false,",
+            " flag_unbuf: false,",
+            " flag_help: false,",
+            " flag_version: false,",
+            " arg_params: [\"f1\"] }"
+        );
+        assert_eq!(thing, expect);
+    }
 }
@@ -0,0 +1,49 @@
+//! example: curl with argparse! macro
+//!
+//! `curl.cmd.argparse.rs.txt` is generated from `curl.cmd.txt` by xtask.
+
+include!("curl.cmd.argparse.rs.txt");
+
+fn main() {
+    let env_args: Vec<String> = std::env::args().skip(1).collect();
+    let env_args: Vec<&str> = env_args.iter().map(std::string::String::as_str).collect();
+    let conf = match CmdOptConf::parse(&env_args) {
+        Ok(conf) => conf,
+        Err(err) => {
+            const TRY_HELP_MSG: &str = "Try --help for help.";
+            eprintln!("{err}\n{TRY_HELP_MSG}");
+            std::process::exit(1);
+        }
+    };
+    eprintln!("{conf:?}");
+}
+
+#[cfg(not(feature = "long_only"))]
+mod example {
+    #[test]
+    fn test_curl_macro_1() {
+        #[rustfmt::skip]
+        let args = [
+            "-a", "--connect-timeout", "50", "--cacert", "ca.pem",
+            "--continue-at=100", "url1",
+        ];
+        let conf = super::CmdOptConf::parse(&args).unwrap();
+        assert!(conf.flg_append);
+        assert_eq!(conf.opt_connect_timeout, 50);
+        assert_eq!(conf.opt_cacert, std::path::PathBuf::from("ca.pem"));
+        assert_eq!(conf.opt_continue_at, 100);
+        assert_eq!(conf.arg_params, vec!["url1".to_string()]);
+    }
+    #[test]
+    fn test_curl_macro_2() {
+        let args = ["--connect-timeout", "abc"];
+        let err = super::CmdOptConf::parse(&args).unwrap_err();
+        assert_eq!(
+            format!("{err}"),
+            "Invalid option argument: connect-timeout: abc"
+        );
+        let args = ["-H"];
+        let conf = super::CmdOptConf::parse(&args).unwrap();
+        assert!(flood_tide::HelpVersion::is_help(&conf));
+    }
+}
@@ -362,9 +362,13 @@ const OPT_ARY: [Opt;66] = [
 
 #[rustfmt::skip]
 const OPT_ARY_SHO_IDX: [(u8,usize);2] = [
-    (b'H',64), (b'V',65)
+    (b'H',0), (b'V',1)
 ];
 
+// the options with the stream specifier, like `-c:v`
+#[cfg(feature = "stream_spec")]
+const STREAM_SPEC_NUMS: [OptNum; 2] = [CmdOP::Codec.to(), CmdOP::VideoBitrate.to()];
+
 //----------------------------------------------------------------------
 #[allow(dead_code)]
 #[derive(Debug, Default)]
@@ -736,6 +740,8 @@ fn parse_cmdopts(program: &str, args: Vec<&str>) -> Result<CmdOptConf, OptParseE
     };
     //
     let lex = Lex::create_with(&OPT_ARY, &OPT_ARY_SHO_IDX);
+    #[cfg(feature = "stream_spec")]
+    let lex = lex.stream_spec(&STREAM_SPEC_NUMS);
     let tokens = match lex.tokens_from(&args) {
         Ok(t) => t,
         Err(errs) => {
@@ -1168,4 +1174,23 @@ mod example {
             }
         };
     }
+    #[cfg(feature = "stream_spec")]
+    #[test]
+    fn test_ffmpeg_6() {
+        let program = "test-ffmpeg";
+        #[rustfmt::skip]
+        let args = vec!["-i", "in.mp4", "-c:v", "copy", "-b:a", "64k", "out.mp4"];
+        //
+        let conf = match super::parse_cmdopts(program, args) {
+            Ok(conf) => conf,
+            Err(err) => {
+                assert_eq!(format!("{}", err), "");
+                unreachable!();
+            }
+        };
+        //
+        assert_eq!(conf.out_file.file, "out.mp4");
+        assert_eq!(conf.out_file.conf.pfl_codec, "copy");
+        assert_eq!(conf.out_file.conf.vid_video_bitrate, "64k");
+    }
 }
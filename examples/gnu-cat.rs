@@ -2,7 +2,9 @@
 
 use flood_tide::parse_simple_gnu_style;
 use flood_tide::Arg;
+use flood_tide::Expand;
 use flood_tide::HelpVersion;
+use flood_tide::Lex;
 use flood_tide::NameVal;
 use flood_tide::Opt;
 use flood_tide::OptNum;
@@ -88,6 +90,14 @@ const OPT_ARY_SHO_IDX: [(u8,usize);11] = [
     (b'b',4), (b'e',0), (b'n',3), (b's',9), (b't',1), (b'v',7)
 ];
 
+// `-A`, `-e` and `-t` are `@expand -vET`, `@expand -vE` and `@expand -vT` of xgen
+#[rustfmt::skip]
+const EXPAND_ARY: [Expand;3] = [
+    (CmdOP::ShowAll.to(), &[(CmdOP::Visual.to(), None), (CmdOP::Ends.to(), None), (CmdOP::Tab.to(), None)]),
+    (CmdOP::E.to(),       &[(CmdOP::Visual.to(), None), (CmdOP::Ends.to(), None)]),
+    (CmdOP::T.to(),       &[(CmdOP::Visual.to(), None), (CmdOP::Tab.to(), None)]),
+];
+
 //----------------------------------------------------------------------
 #[derive(Debug, Default)]
 struct CmdOptConf {
@@ -112,6 +122,9 @@ impl HelpVersion for CmdOptConf {
     fn is_version(&self) -> bool {
         self.flag_version
     }
+    fn setup_lex<'a>(&self, lex: Lex<'a>) -> Lex<'a> {
+        lex.expand(&EXPAND_ARY)
+    }
 }
 
 //----------------------------------------------------------------------
@@ -157,19 +170,12 @@ fn parse_match(conf: &mut CmdOptConf, nv: &NameVal<'_>) -> Result<(), OptParseEr
             print_version_and_exit(conf);
         }
         //
-        CmdOP::ShowAll => {
-            conf.flag_tab = true;
-            conf.flag_ends = true;
-            conf.flag_visual = true;
-        }
+        // the expanded options follow
+        CmdOP::ShowAll | CmdOP::E | CmdOP::T => {}
         CmdOP::NumberNB => {
             conf.flag_number_nb = true;
             conf.flag_number = false;
         }
-        CmdOP::E => {
-            conf.flag_visual = true;
-            conf.flag_ends = true;
-        }
         CmdOP::Ends => {
             conf.flag_ends = true;
         }
@@ -179,10 +185,6 @@ fn parse_match(conf: &mut CmdOptConf, nv: &NameVal<'_>) -> Result<(), OptParseEr
         CmdOP::Squeeze => {
             conf.flag_squeeze = true;
         }
-        CmdOP::T => {
-            conf.flag_tab = true;
-            conf.flag_visual = true;
-        }
         CmdOP::Tab => {
             conf.flag_tab = true;
         }
@@ -382,4 +384,89 @@ mod example {
             }
         };
     }
+    //
+    #[test]
+    fn test_gnu_cat_7() {
+        let program = "test-gnu-cat";
+        #[rustfmt::skip]
+        let args = vec!["-", "-n", "f1"];
+        //
+        let conf = match super::parse_cmdopts(program, args) {
+            Ok(conf) => conf,
+            Err(err) => {
+                assert_eq!(format!("{}", err), "");
+                unreachable!();
+            }
+        };
+        //
+        let thing = format!("{:?}", conf);
+        let expect = concat!(
+            "CmdOptConf {",
+            " opt_program: \"test-gnu-cat\",",
+            " flag_tab: false,",
+            " flag_ends: false,",
+            " flag_visual: false,",
+            " flag_number_nb: false,",
+            " flag_number: false,",
+            " flag_squeeze: false,",
+            " flag_help: false,",
+            " flag_version: false,",
+            " arg_params: [\"-\", \"-n\", \"f1\"] }"
+        );
+        assert_eq!(thing, expect);
+    }
+    //
+    #[test]
+    fn test_gnu_cat_expand() {
+        use super::CmdOP;
+        let program = "test-gnu-cat";
+        #[rustfmt::skip]
+        let args = vec!["-et", "-A", "f1"];
+        //
+        let lex = flood_tide::Lex::create_with(&super::OPT_ARY, &super::OPT_ARY_SHO_IDX)
+            .expand(&super::EXPAND_ARY);
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(err) => {
+                assert_eq!(format!("{}", err), "");
+                unreachable!();
+            }
+        };
+        let thing: Vec<CmdOP> = tokens
+            .namevals
+            .iter()
+            .map(|nv| CmdOP::from(nv.opt.num))
+            .collect();
+        #[rustfmt::skip]
+        let expect = vec![
+            CmdOP::E, CmdOP::Visual, CmdOP::Ends,
+            CmdOP::T, CmdOP::Visual, CmdOP::Tab,
+            CmdOP::ShowAll, CmdOP::Visual, CmdOP::Ends, CmdOP::Tab,
+        ];
+        assert_eq!(thing, expect);
+        //
+        let conf = match super::parse_cmdopts(program, vec!["-e", "f1"]) {
+            Ok(conf) => conf,
+            Err(err) => {
+                assert_eq!(format!("{}", err), "");
+                unreachable!();
+            }
+        };
+        //
+        let thing = format!("{:?}", conf);
+        let expect = concat!(
+            "CmdOptConf {",
+            " opt_program: \"test-gnu-cat\",",
+            " flag_tab: false,",
+            " flag_ends: true,",
+            " flag_visual: true,",
+            " flag_number_nb: false,",
+            " flag_number: false,",
+            " flag_squeeze: false,",
+            " flag_help: false,",
+            " flag_version: false,",
+            " arg_params: [\"f1\"] }"
+        );
+        assert_eq!(thing, expect);
+    }
 }
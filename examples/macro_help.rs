@@ -21,4 +21,12 @@ fn main() {
     let conf = MyConf::parse(&args).unwrap();
     println!("is_version: {}", conf.is_version());
     assert!(conf.is_version());
+
+    // the help wins over the invalid option
+    #[cfg(feature = "help_version")]
+    {
+        let args = ["--bogus", "--help"];
+        let conf = MyConf::parse(&args).unwrap();
+        assert!(conf.is_help());
+    }
 }
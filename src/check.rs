@@ -4,8 +4,15 @@
 
 #[cfg(feature = "no_std")]
 use core::cmp::Ordering;
+#[cfg(feature = "no_std")]
+use core::fmt::{Display, Error, Formatter};
 #[cfg(not(feature = "no_std"))]
 use std::cmp::Ordering;
+#[cfg(not(feature = "no_std"))]
+use std::fmt::{Display, Error, Formatter};
+
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
 
 use super::Opt;
 
@@ -92,3 +99,157 @@ pub fn check_sorted_opt_ary_and_sho_idx_ary_with(
     //
     true
 }
+
+/// Diagnostic of the parse tables, as the result of [`validate`].
+///
+/// The `usize` is the index into `opt_ary` or `sho_idx_ary`
+/// as noted on each variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// `opt_ary[n]` is not sorted by long name
+    UnsortedOpt(usize),
+    /// `opt_ary[n]` has the same long name as the previous row
+    DuplicateLong(usize),
+    /// `opt_ary[n]` has the same `num` as an earlier row, like the alias
+    DuplicateNum(usize),
+    /// `opt_ary[n]` has a long name containing `=`
+    LongContainsEq(usize),
+    /// `opt_ary[n]` has a long name starting with `-`, like `-help` of long only
+    LongStartsWithDash(usize),
+    /// `sho_idx_ary[n]` is not sorted by short name
+    UnsortedShoIdx(usize),
+    /// `sho_idx_ary[n]` has the same short name as the previous entry
+    DuplicateShort(usize),
+    /// `sho_idx_ary[n]` points out of `opt_ary`
+    DanglingShoIdx(usize),
+    /// `sho_idx_ary[n]` points at a row that has another short name
+    WrongShoIdx(usize),
+}
+
+/// Severity of [`Diagnostic`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// the tables do not work as expected
+    Error,
+    /// the tables work, but it may be a mistake
+    Warning,
+}
+
+impl Diagnostic {
+    /// the severity of the diagnostic.
+    ///
+    /// `DuplicateNum` and `LongStartsWithDash` are [`Severity::Warning`],
+    /// because the aliases share the `num`, and the long only tables
+    /// have the long names like `-help` for `--help`.
+    pub fn severity(&self) -> Severity {
+        match *self {
+            Diagnostic::DuplicateNum(_) | Diagnostic::LongStartsWithDash(_) => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        use self::Diagnostic::*;
+        //
+        match *self {
+            UnsortedOpt(n) => write!(fmt, "opt_ary[{n}]: not sorted by long name"),
+            DuplicateLong(n) => write!(fmt, "opt_ary[{n}]: duplicate long name"),
+            DuplicateNum(n) => write!(fmt, "opt_ary[{n}]: duplicate num"),
+            LongContainsEq(n) => write!(fmt, "opt_ary[{n}]: long name contains '='"),
+            LongStartsWithDash(n) => write!(fmt, "opt_ary[{n}]: long name starts with '-'"),
+            UnsortedShoIdx(n) => write!(fmt, "sho_idx_ary[{n}]: not sorted by short name"),
+            DuplicateShort(n) => write!(fmt, "sho_idx_ary[{n}]: duplicate short name"),
+            DanglingShoIdx(n) => write!(fmt, "sho_idx_ary[{n}]: index out of opt_ary"),
+            WrongShoIdx(n) => write!(fmt, "sho_idx_ary[{n}]: index points at wrong row"),
+        }
+    }
+}
+
+/// Validate opt ary table and sho idx ary table.
+///
+/// This is the verbose version of [`check_sorted_opt_ary_and_sho_idx_ary_with`],
+/// it returns all the diagnostics instead of `bool`.
+/// - return:
+///    - empty:  ok
+///    - others: diagnostics in the order of `opt_ary`, `sho_idx_ary`
+/// # Examples
+/// ```
+/// #[cfg(feature = "option_argument")]
+/// {
+///     use flood_tide::check::{self, Diagnostic};
+///     use flood_tide::{Arg, Opt};
+///
+///     #[rustfmt::skip]
+///     let opt_ary = [
+///         Opt { sho: b'b', lon: "barn", has: Arg::No,  num: 1, },
+///         Opt { sho: b'a', lon: "arm",  has: Arg::No,  num: 2, },
+///         Opt { sho: 0u8,  lon: "eat",  has: Arg::Yes, num: 2, },
+///     ];
+///     #[rustfmt::skip]
+///     let opt_ary_sho_idx = [(b'a',1),(b'b',1)];
+///     assert_eq!(
+///         check::validate(&opt_ary, &opt_ary_sho_idx),
+///         vec![
+///             Diagnostic::UnsortedOpt(1),
+///             Diagnostic::DuplicateNum(2),
+///             Diagnostic::WrongShoIdx(1),
+///         ]
+///     );
+/// }
+/// ```
+pub fn validate(opt_ary: &[Opt], sho_idx_ary: &[(u8, usize)]) -> Vec<Diagnostic> {
+    let mut diags = Vec::new();
+    //
+    // the rows of the same num as an earlier row, by sorting the nums once
+    let mut nums: Vec<_> = opt_ary
+        .iter()
+        .enumerate()
+        .map(|(i, o)| (o.num, i))
+        .collect();
+    nums.sort_unstable();
+    let mut dup_nums = Vec::new();
+    dup_nums.resize(opt_ary.len(), false);
+    for w in nums.windows(2) {
+        if w[0].0 == w[1].0 {
+            dup_nums[w[1].1] = true;
+        }
+    }
+    //
+    let mut prev_lon: &str = "";
+    for (i, o) in opt_ary.iter().enumerate() {
+        if prev_lon > o.lon {
+            diags.push(Diagnostic::UnsortedOpt(i));
+        } else if !prev_lon.is_empty() && prev_lon == o.lon {
+            diags.push(Diagnostic::DuplicateLong(i));
+        }
+        if dup_nums[i] {
+            diags.push(Diagnostic::DuplicateNum(i));
+        }
+        if o.lon.contains('=') {
+            diags.push(Diagnostic::LongContainsEq(i));
+        }
+        if o.lon.starts_with('-') {
+            diags.push(Diagnostic::LongStartsWithDash(i));
+        }
+        prev_lon = o.lon;
+    }
+    //
+    let mut prev_sho: u8 = 0u8;
+    for (i, &(sho, idx)) in sho_idx_ary.iter().enumerate() {
+        if prev_sho > sho {
+            diags.push(Diagnostic::UnsortedShoIdx(i));
+        } else if prev_sho > 0 && prev_sho == sho {
+            diags.push(Diagnostic::DuplicateShort(i));
+        }
+        match opt_ary.get(idx) {
+            None => diags.push(Diagnostic::DanglingShoIdx(i)),
+            Some(o) if o.sho != sho => diags.push(Diagnostic::WrongShoIdx(i)),
+            Some(_) => {}
+        }
+        prev_sho = sho;
+    }
+    //
+    diags
+}
@@ -48,10 +48,16 @@ pub enum OptParseErrorKind {
     #[cfg(feature = "subcommand")]
     MissingSubcommand,
     //
-    #[cfg(feature = "abbreviate")]
+    #[cfg(any(feature = "abbreviate", feature = "ignore_case"))]
     AmbiguousOption,
-    #[cfg(all(feature = "abbreviate", feature = "subcommand"))]
+    #[cfg(all(
+        any(feature = "abbreviate", feature = "ignore_case"),
+        feature = "subcommand"
+    ))]
     AmbiguousSubcommand,
+    //
+    #[cfg(any(feature = "occurrence", feature = "dox"))]
+    RepeatedOption,
 }
 
 /// Single option parse error
@@ -89,7 +95,11 @@ impl OptParseError {
             desc2: None,
         }
     }
-    #[cfg(any(feature = "option_argument", feature = "abbreviate"))]
+    #[cfg(any(
+        feature = "option_argument",
+        feature = "abbreviate",
+        feature = "ignore_case"
+    ))]
     #[inline(never)]
     fn new_p2(a_kind: OptParseErrorKind, a_desc1: &str, a_desc2: &str) -> Self {
         let mut r = Self::new_p1(a_kind, a_desc1);
@@ -141,14 +151,25 @@ impl OptParseError {
         Self::new_p1(OptParseErrorKind::MissingSubcommand, desc1)
     }
     //
-    #[cfg(any(feature = "abbreviate", feature = "dox"))]
+    #[cfg(any(feature = "abbreviate", feature = "ignore_case", feature = "dox"))]
     pub fn ambiguous_option(desc1: &str, desc2: &str) -> Self {
         Self::new_p2(OptParseErrorKind::AmbiguousOption, desc1, desc2)
     }
-    #[cfg(any(all(feature = "abbreviate", feature = "subcommand"), feature = "dox"))]
+    #[cfg(any(
+        all(
+            any(feature = "abbreviate", feature = "ignore_case"),
+            feature = "subcommand"
+        ),
+        feature = "dox"
+    ))]
     pub fn ambiguous_subcommand(desc1: &str, desc2: &str) -> Self {
         Self::new_p2(OptParseErrorKind::AmbiguousSubcommand, desc1, desc2)
     }
+    //
+    #[cfg(any(feature = "occurrence", feature = "dox"))]
+    pub fn repeated_option(desc1: &str) -> Self {
+        Self::new_p1(OptParseErrorKind::RepeatedOption, desc1)
+    }
 }
 
 impl Display for OptParseError {
@@ -180,10 +201,16 @@ impl Display for OptParseError {
             #[cfg(feature = "subcommand")]
             MissingSubcommand => "Missing subcommand",
             //
-            #[cfg(feature = "abbreviate")]
+            #[cfg(any(feature = "abbreviate", feature = "ignore_case"))]
             AmbiguousOption => "Ambiguous option",
-            #[cfg(all(feature = "abbreviate", feature = "subcommand"))]
+            #[cfg(all(
+                any(feature = "abbreviate", feature = "ignore_case"),
+                feature = "subcommand"
+            ))]
             AmbiguousSubcommand => "Ambiguous subcommand",
+            //
+            #[cfg(any(feature = "occurrence", feature = "dox"))]
+            RepeatedOption => "Repeated option",
         };
         match self.desc2 {
             Some(ref s) => write!(fmt, "{}: {}: {}", msg, &self.desc1, &s),
@@ -241,3 +268,91 @@ impl Display for OptParseErrors {
 }
 #[cfg(any(not(feature = "no_std"), feature = "dox"))]
 impl std::error::Error for OptParseErrors {}
+
+/// Kind of the option parse warning
+#[cfg(any(feature = "warnings", feature = "dox"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OptParseWarningKind {
+    DeprecatedOption,
+    OverriddenOption,
+    #[cfg(any(feature = "abbreviate", feature = "dox"))]
+    AbbreviatedOption,
+    /// the warning of `parse_match`, that has no message prefix
+    Other,
+}
+
+/// Single option parse warning, that is not fatal
+#[cfg(any(feature = "warnings", feature = "dox"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptParseWarning {
+    kind: OptParseWarningKind,
+    desc1: String,
+    desc2: Option<String>,
+}
+
+#[cfg(any(feature = "warnings", feature = "dox"))]
+impl OptParseWarning {
+    pub fn kind(&self) -> OptParseWarningKind {
+        self.kind.clone()
+    }
+    pub fn desc1_str(&self) -> &str {
+        self.desc1.as_str()
+    }
+}
+
+#[cfg(any(feature = "warnings", feature = "dox"))]
+impl OptParseWarning {
+    #[inline(never)]
+    fn new_p2(a_kind: OptParseWarningKind, a_desc1: &str, a_desc2: &str) -> Self {
+        Self {
+            kind: a_kind,
+            desc1: a_desc1.to_string(),
+            desc2: if a_desc2.is_empty() {
+                None
+            } else {
+                Some(a_desc2.to_string())
+            },
+        }
+    }
+    /// the `desc2` is the hint, like `use --color`. the empty is none.
+    pub fn deprecated_option(desc1: &str, desc2: &str) -> Self {
+        Self::new_p2(OptParseWarningKind::DeprecatedOption, desc1, desc2)
+    }
+    /// the `desc2` is the overridden value. the empty is none.
+    pub fn overridden_option(desc1: &str, desc2: &str) -> Self {
+        Self::new_p2(OptParseWarningKind::OverriddenOption, desc1, desc2)
+    }
+    /// the `desc2` is the hint, like `use --verbose`. the empty is none.
+    #[cfg(any(feature = "abbreviate", feature = "dox"))]
+    pub fn abbreviated_option(desc1: &str, desc2: &str) -> Self {
+        Self::new_p2(OptParseWarningKind::AbbreviatedOption, desc1, desc2)
+    }
+    /// the own warning, like of `parse_match`. the empty `desc2` is none.
+    pub fn other(desc1: &str, desc2: &str) -> Self {
+        Self::new_p2(OptParseWarningKind::Other, desc1, desc2)
+    }
+}
+
+#[cfg(any(feature = "warnings", feature = "dox"))]
+impl Display for OptParseWarning {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        use self::OptParseWarningKind::*;
+        //
+        let msg: &str = match self.kind {
+            DeprecatedOption => "Deprecated option",
+            OverriddenOption => "Overridden option",
+            #[cfg(any(feature = "abbreviate", feature = "dox"))]
+            AbbreviatedOption => "Abbreviated option",
+            Other => {
+                return match self.desc2 {
+                    Some(ref s) => write!(fmt, "{}: {}", &self.desc1, &s),
+                    None => write!(fmt, "{}", &self.desc1),
+                };
+            }
+        };
+        match self.desc2 {
+            Some(ref s) => write!(fmt, "{}: {}: {}", msg, &self.desc1, &s),
+            None => write!(fmt, "{}: {}", msg, &self.desc1),
+        }
+    }
+}
@@ -11,6 +11,26 @@ Command line flag and option parse utilities.
 - single long options (like `-long`)
 - abbreviate long options (like `--abbr` ::= `--abbreviate`)
 - single error or multiple errors
+- pass unknown options through to the other program (like `parse_known_args`)
+- ordered events of options and free arguments (like `ffmpeg` and `find`)
+- option groups delimited by the anchor options (like `ffmpeg -i`)
+- stream specifier suffix of the option name (like `ffmpeg -c:v`)
+- sub-options of the option argument (like `mount -o ro,uid=1000`)
+- option expansions (like `cat -A` ::= `-vET`)
+- option aliases of the same number (like `--color` and `--colour`)
+- deprecated options with the non-fatal warnings
+- ASCII case-insensitive long options and subcommands (like `--VERBOSE`)
+- the secondary prefix of options (like `set +x` and `set +o name`)
+- negative numbers as free arguments, and numeric shorthand option (like `head -20`)
+- optional option argument of the next argument (like `sed -i .bak`)
+- strict option argument not starting with `-` (like `--output --verbose` is an error)
+- policies of `--name=value` only, `-o value` only, `-o=value` and no combined short flags
+- long options through the short option (like `-W foo` ::= `--foo` of GNU)
+- the arguments after `--` apart from the operands, to pass through (like `cargo run -- args`)
+- the help and the version winning over the other errors (like `prog --bogus --help`)
+- the non-fatal warnings of the overridden options and the abbreviations
+- the policies of the repeated options: the last, the first, the error or the accumulation
+- the partial tokens alongside the multiple errors (like honouring `--quiet` with the errors)
 - only UTF-8 arguments
 - it can be used optimally by a compile switch with many features.
 - minimum support rustc 1.60.0 (7737e0b5c 2022-04-04)
@@ -70,6 +90,13 @@ use alloc::string::{String, ToString};
 #[cfg(feature = "no_std")]
 use alloc::vec::Vec;
 
+#[cfg(any(feature = "pass_unknown", feature = "dox"))]
+#[cfg(feature = "no_std")]
+use alloc::borrow::Cow;
+#[cfg(any(feature = "pass_unknown", feature = "dox"))]
+#[cfg(not(feature = "no_std"))]
+use std::borrow::Cow;
+
 pub mod check;
 pub mod err;
 pub mod macro_util;
@@ -96,10 +123,29 @@ pub type OptNum = u8;
 
 pub use err::OptParseErrorKind;
 
+#[cfg(any(feature = "warnings", feature = "dox"))]
+pub use err::{OptParseWarning, OptParseWarningKind};
+
 /// check help and version of conf
 pub trait HelpVersion {
     fn is_help(&self) -> bool;
     fn is_version(&self) -> bool;
+    /// option nums of the help and the version, that win over the other errors
+    /// at the compiling with feature = "help_version". The default is nothing.
+    fn help_version_nums(&self) -> &'static [OptNum] {
+        &[]
+    }
+    /// occurrence policies of the options, to [`Lex::occurrence()`] in
+    /// [`parse_simple_gnu_style()`]. The default is nothing.
+    #[cfg(any(feature = "occurrence", feature = "dox"))]
+    fn occurrences(&self) -> &'static [(OptNum, Occurrence)] {
+        &[]
+    }
+    /// setup of the lex in `parse_simple_gnu_style()`, like `Lex::deprecated()`
+    /// or `Lex::single_value()`. The default is as it is.
+    fn setup_lex<'a>(&self, lex: Lex<'a>) -> Lex<'a> {
+        lex
+    }
 }
 
 /// setter subcmd of conf
@@ -108,6 +154,8 @@ pub trait SubCommand {
 }
 
 /// Parse simple gnu style.
+///
+/// The unknown options are the errors of [`OptParseErrorKind::InvalidOption`].
 #[cfg(any(feature = "stop_at_mm", feature = "dox"))]
 pub fn parse_simple_gnu_style<'a, T, F>(
     conf: &mut T,
@@ -120,23 +168,105 @@ where
     F: Fn(&mut T, &NameVal<'_>) -> Result<(), OptParseError>,
     T: HelpVersion,
 {
-    let lex = Lex::create_with(opt_ary, sho_idx_ary);
+    let (free, res, _) = parse_gnu_style(conf, opt_ary, sho_idx_ary, args, parse_match);
+    (free, res)
+}
+
+/// Parse simple gnu style, and return the non-fatal warnings.
+///
+/// The warnings are [`Tokens::warnings`], and then the ones pushed by `parse_match`.
+/// The warnings of the lex are returned with the errors of the lex too,
+/// except at the compiling with feature = "single_error".
+#[cfg(any(all(feature = "stop_at_mm", feature = "warnings"), feature = "dox"))]
+pub fn parse_simple_gnu_style_with_warnings<'a, T, F>(
+    conf: &mut T,
+    opt_ary: &'a [Opt],
+    sho_idx_ary: &'a [(u8, usize)],
+    args: &'a [&'a str],
+    parse_match: F,
+) -> (Option<Vec<String>>, Result<(), OpErr>, Vec<OptParseWarning>)
+where
+    F: Fn(&mut T, &NameVal<'_>, &mut Vec<OptParseWarning>) -> Result<(), OptParseError>,
+    T: HelpVersion,
+{
+    let mut pushed = Vec::new();
+    let (free, res, mut warnings) =
+        parse_gnu_style(conf, opt_ary, sho_idx_ary, args, |conf, nv| {
+            parse_match(conf, nv, &mut pushed)
+        });
+    warnings.append(&mut pushed);
+    (free, res, warnings)
+}
+
+// the warnings of the lex, nothing without feature = "warnings"
+#[cfg(any(feature = "stop_at_mm", feature = "dox"))]
+#[cfg(any(feature = "warnings", feature = "dox"))]
+type LexWarnings = Vec<OptParseWarning>;
+#[cfg(any(feature = "stop_at_mm", feature = "dox"))]
+#[cfg(not(any(feature = "warnings", feature = "dox")))]
+type LexWarnings = ();
+
+// the lex of conf
+#[cfg(any(feature = "stop_at_mm", feature = "dox"))]
+fn conf_lex<'a, T: HelpVersion>(conf: &T, lex: Lex<'a>) -> Lex<'a> {
+    #[cfg(feature = "help_version")]
+    let lex = lex.help_version(conf.help_version_nums());
+    #[cfg(feature = "occurrence")]
+    let lex = lex.occurrence(conf.occurrences());
+    conf.setup_lex(lex)
+}
+
+// the body of parse_simple_gnu_style() and parse_simple_gnu_style_with_warnings()
+#[cfg(any(feature = "stop_at_mm", feature = "dox"))]
+fn parse_gnu_style<'a, T, F>(
+    conf: &mut T,
+    opt_ary: &'a [Opt],
+    sho_idx_ary: &'a [(u8, usize)],
+    args: &'a [&'a str],
+    mut parse_match: F,
+) -> (Option<Vec<String>>, Result<(), OpErr>, LexWarnings)
+where
+    F: FnMut(&mut T, &NameVal<'_>) -> Result<(), OptParseError>,
+    T: HelpVersion,
+{
+    let lex = conf_lex(conf, Lex::create_with(opt_ary, sho_idx_ary));
+    // the warnings survive the errors of the lex
+    #[cfg(not(feature = "single_error"))]
+    let tokens = {
+        let partial = lex.tokens_partial_from(args);
+        if !partial.errs.is_empty() {
+            #[cfg(any(feature = "warnings", feature = "dox"))]
+            return (None, Err(partial.errs), partial.tokens.warnings);
+            #[cfg(not(any(feature = "warnings", feature = "dox")))]
+            return (None, Err(partial.errs), ());
+        }
+        partial.tokens
+    };
+    #[cfg(feature = "single_error")]
     let tokens = match lex.tokens_from(args) {
         Ok(t) => t,
-        Err(errs) => {
-            return (None, Err(errs));
+        Err(err) => {
+            return (None, Err(err), LexWarnings::default());
         }
     };
     //
     #[cfg(not(feature = "single_error"))]
     let mut errs = OptParseErrors::new();
+    #[cfg(feature = "help_version")]
+    let is_help_version = lex.has_help_version(&tokens.namevals);
+    #[cfg(any(feature = "warnings", feature = "dox"))]
+    let warnings = tokens.warnings;
+    #[cfg(not(any(feature = "warnings", feature = "dox")))]
+    let warnings = ();
     //
     for nv in tokens.namevals.iter() {
         match parse_match(conf, nv) {
             Ok(_) => {}
+            #[cfg(feature = "help_version")]
+            Err(_) if is_help_version => {}
             Err(err) => {
                 #[cfg(feature = "single_error")]
-                return (None, Err(err));
+                return (None, Err(err), warnings);
                 #[cfg(not(feature = "single_error"))]
                 errs.push(err);
             }
@@ -150,12 +280,12 @@ where
     v.extend(tokens.free.iter().map(|&s| s.to_string()));
     //
     #[cfg(feature = "single_error")]
-    return (Some(v), Ok(()));
+    return (Some(v), Ok(()), warnings);
     #[cfg(not(feature = "single_error"))]
     if errs.is_empty() {
-        (Some(v), Ok(()))
+        (Some(v), Ok(()), warnings)
     } else {
-        (Some(v), Err(errs))
+        (Some(v), Err(errs), warnings)
     }
 }
 
@@ -173,7 +303,7 @@ where
     F: Fn(&mut T, &NameVal<'_>) -> Result<(), OptParseError>,
     T: HelpVersion + SubCommand,
 {
-    let lex = Lex::create_with(opt_ary, sho_idx_ary).subcmd(subcmds);
+    let lex = conf_lex(conf, Lex::create_with(opt_ary, sho_idx_ary).subcmd(subcmds));
     let tokens = match lex.tokens_from(args) {
         Ok(t) => t,
         Err(errs) => {
@@ -183,10 +313,14 @@ where
     //
     #[cfg(not(feature = "single_error"))]
     let mut errs = OptParseErrors::new();
+    #[cfg(feature = "help_version")]
+    let is_help_version = lex.has_help_version(&tokens.namevals);
     //
     for nv in tokens.namevals.iter() {
         match parse_match(conf, nv) {
             Ok(_) => {}
+            #[cfg(feature = "help_version")]
+            Err(_) if is_help_version => {}
             Err(err) => {
                 #[cfg(feature = "single_error")]
                 return (None, Err(err));
@@ -201,6 +335,8 @@ where
     //
     match tokens.subcmd {
         Some(s) => conf.set_subcmd(String::from(s)),
+        #[cfg(feature = "help_version")]
+        None if conf.is_help() || conf.is_version() => {}
         None => {
             #[cfg(feature = "single_error")]
             return (None, Err(OptParseError::missing_subcommand("<command>")));
@@ -229,10 +365,119 @@ pub enum Arg {
     No = 0,
     Yes,
     Maybe,
+    /// like `Maybe`, and takes the next argument that is not
+    /// an option or a subcommand, like `-i .bak` of `bsd-sed`
+    MaybeNext,
+}
+
+// the rest of the command line arguments
+type Cursor<'a> = core::slice::Iter<'a, &'a str>;
+
+/// Strict value policy of [`Lex::strict_value()`]
+///
+/// # Examples
+/// ```
+/// #[cfg(feature = "strict_value")]
+/// {
+///     use flood_tide::StrictValue;
+///
+///     // all options, and the negative numbers are accepted
+///     let policy = StrictValue {
+///         negative_number: true,
+///         ..Default::default()
+///     };
+///     // only the option of num 1, and the lone `-` is accepted
+///     let policy = StrictValue {
+///         nums: &[1],
+///         lone_minus: true,
+///         ..Default::default()
+///     };
+/// }
+/// ```
+#[cfg(any(feature = "strict_value", feature = "dox"))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StrictValue<'a> {
+    /// the option nums of the policy, all options if it is empty
+    pub nums: &'a [OptNum],
+    /// the lone `-`, stdin or stdout, is the option argument
+    pub lone_minus: bool,
+    /// the negative numbers, like `-5` and `-1.5`, are the option argument
+    pub negative_number: bool,
+}
+
+/// Value policy of [`Lex::value_policy()`]
+///
+/// # Examples
+/// ```
+/// #[cfg(feature = "value_policy")]
+/// {
+///     use flood_tide::ValuePolicy;
+///
+///     // `--name=value`, `-o value` and `-o=value` only
+///     let policy = ValuePolicy {
+///         long_equals_only: true,
+///         short_separate_only: true,
+///         short_strip_equals: true,
+///         ..Default::default()
+///     };
+/// }
+/// ```
+#[cfg(any(feature = "value_policy", feature = "dox"))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ValuePolicy {
+    /// the long option argument only by `=`, like `--name=value`
+    pub long_equals_only: bool,
+    /// the short option argument only by the next argument, like `-o value`
+    pub short_separate_only: bool,
+    /// the combined short flags, like `-abc`, are not accepted
+    pub no_cluster: bool,
+    /// the `=` of `-o=value` is stripped, and it is accepted
+    /// even if `short_separate_only`
+    pub short_strip_equals: bool,
+}
+
+/// Occurrence policy of the repeated option, of [`Lex::occurrence()`]
+///
+/// # Examples
+/// ```
+/// #[cfg(feature = "occurrence")]
+/// #[cfg(not(feature = "long_only"))]
+/// {
+///     use flood_tide::{Arg, Lex, Occurrence, Opt, OptNum};
+///
+///     #[rustfmt::skip]
+///     const OPT_ARY: [Opt;2] = [
+///         Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: 1, },
+///         Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: 2, },
+///     ];
+///     const OPT_ARY_SHO_IDX: [(u8,usize);2] = [(b'o',0),(b'v',1)];
+///     const OCCURRENCE_ARY: [(OptNum, Occurrence);1] = [(1, Occurrence::First)];
+///
+///     let args = ["-o", "a", "-v", "--output=b", "-v"];
+///     let lex = Lex::create_with(&OPT_ARY, &OPT_ARY_SHO_IDX).occurrence(&OCCURRENCE_ARY);
+///     let tokens = lex.tokens_from(&args).unwrap();
+///     let thing: Vec<_> = tokens.namevals.iter().map(|nv| (nv.opt.num, nv.val)).collect();
+///     assert_eq!(thing, [(1, Some("a")), (2, None), (2, None)]);
+/// }
+/// ```
+#[cfg(any(feature = "occurrence", feature = "dox"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Occurrence {
+    /// the last one wins, the previous ones are removed
+    Last,
+    /// the first one wins, the following ones are removed
+    First,
+    /// the repeated one is [`OptParseErrorKind::RepeatedOption`]
+    Error,
+    /// all are kept in order, like the option without the policy
+    Accumulate,
 }
 
 /// Record type of opt ary table
 ///
+/// The rows with the same `num` are the aliases of an option,
+/// like `--color` and `--colour`. The abbreviation of the aliases is not ambiguous.
+///
 /// # Examples
 /// ```
 /// #[cfg(feature = "option_argument")]
@@ -282,14 +527,51 @@ impl Opt<'_> {
     }
 }
 
+/// Record type of expand ary table
+///
+/// The option of the `OptNum` expands to the sequence of the options
+/// with the fixed values, like `-A` of `cat` is `-vET`.
+/// The expand ary table must be sorted by the `OptNum`.
+///
+/// # Examples
+/// ```
+/// #[cfg(feature = "expand")]
+/// {
+///     use flood_tide::{Expand, OptNum};
+///
+///     #[rustfmt::skip]
+///     #[repr(u8)]
+///     enum CmdOP { ShowAll = 1, E, Ends, Visual, };
+///     impl CmdOP { pub const fn to(self) -> OptNum { self as OptNum } }
+///
+///     #[rustfmt::skip]
+///     const EXPAND_ARY: [Expand;2] = [
+///         (CmdOP::ShowAll.to(), &[(CmdOP::Visual.to(), None), (CmdOP::Ends.to(), None)]),
+///         (CmdOP::E.to(),       &[(CmdOP::Visual.to(), None), (CmdOP::Ends.to(), None)]),
+///     ];
+/// }
+/// ```
+#[cfg(any(feature = "expand", feature = "dox"))]
+pub type Expand<'a> = (OptNum, &'a [(OptNum, Option<&'a str>)]);
+
 /// Entity as the result of lex
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct NameVal<'a> {
     pub opt: &'a Opt<'a>,
     #[cfg(any(feature = "option_argument", feature = "dox"))]
     pub val: Option<&'a str>,
     #[cfg(any(feature = "was_long", feature = "dox"))]
     pub was_long: bool,
+    /// stream specifier after `:`, like `v` of `-c:v`
+    #[cfg(any(feature = "stream_spec", feature = "dox"))]
+    pub spec: Option<&'a str>,
+    /// implied by the expansion of the previous option
+    #[cfg(any(feature = "expand", feature = "dox"))]
+    pub implied: bool,
+    /// prefix character of the command line keyword,
+    /// `b'-'` or the secondary prefix, like `b'+'` of `+x`
+    #[cfg(any(feature = "secondary_prefix", feature = "dox"))]
+    pub prefix: u8,
 }
 
 impl NameVal<'_> {
@@ -312,8 +594,127 @@ impl NameVal<'_> {
     }
 }
 
+#[cfg(any(feature = "subopt", feature = "dox"))]
+impl<'a> NameVal<'a> {
+    /// parse the option argument as the sub-options, like `getsubopt(3)`
+    ///
+    /// The option argument is a comma separated list of `key[=value]`,
+    /// like `ro,uid=1000,noatime` of `mount -o`. The keys are looked up
+    /// by the long name of `sub_ary`, that must be sorted as `opt_ary`.
+    /// The `has` of the sub-option is the same as the option,
+    /// and the key is abbreviated at the compiling with feature = "abbreviate".
+    ///
+    /// The error is [`OptParseErrorKind::InvalidOptionArgument`] with the name
+    /// of this option and the bad sub-option.
+    ///
+    /// # Examples
+    /// ```
+    /// #[cfg(feature = "subopt")]
+    /// {
+    ///     use flood_tide::{Arg, NameVal, Opt};
+    ///
+    ///     #[rustfmt::skip]
+    ///     const OPT_ARY: [Opt;1] = [
+    ///         Opt { sho: b'o', lon: "", has: Arg::Yes, num: 1, },
+    ///     ];
+    ///     #[rustfmt::skip]
+    ///     const SUB_ARY: [Opt;3] = [
+    ///         Opt { sho: 0u8, lon: "noatime", has: Arg::No,  num: 1, },
+    ///         Opt { sho: 0u8, lon: "ro",      has: Arg::No,  num: 2, },
+    ///         Opt { sho: 0u8, lon: "uid",     has: Arg::Yes, num: 3, },
+    ///     ];
+    ///
+    ///     let nv = NameVal {
+    ///         opt: &OPT_ARY[0],
+    ///         val: Some("ro,uid=1000,noatime"),
+    ///         #[cfg(feature = "was_long")]
+    ///         was_long: false,
+    ///         #[cfg(feature = "stream_spec")]
+    ///         spec: None,
+    ///         #[cfg(feature = "expand")]
+    ///         implied: false,
+    ///         #[cfg(feature = "secondary_prefix")]
+    ///         prefix: b'-',
+    ///     };
+    ///     let subs = nv.subopts(&SUB_ARY).unwrap();
+    ///     assert_eq!(subs.len(), 3);
+    ///     assert_eq!(subs[1].opt.lon, "uid");
+    ///     assert_eq!(subs[1].val, Some("1000"));
+    ///
+    ///     let nv = NameVal { val: Some("ro,uid"), ..nv };
+    ///     let err = nv.subopts(&SUB_ARY).unwrap_err();
+    ///     assert_eq!(
+    ///         err.to_string(),
+    ///         "Invalid option argument: o: missing sub-option argument: uid"
+    ///     );
+    /// }
+    /// ```
+    pub fn subopts(&self, sub_ary: &'a [Opt<'a>]) -> Result<Vec<NameVal<'a>>, OptParseError> {
+        let mut v_subs = Vec::new();
+        let val = match self.val {
+            Some(val) => val,
+            None => return Ok(v_subs),
+        };
+        let mkerr = |hint: String| OptParseError::invalid_option_argument(&self.name(), &hint);
+        for item in val.split(',').filter(|s| !s.is_empty()) {
+            let (key, sub_val) = match item.find('=') {
+                Some(idx) => (&item[..idx], Some(&item[idx + 1..])),
+                None => (item, None),
+            };
+            let sub_opt = find_subopt(sub_ary, key).map_err(mkerr)?;
+            let sub_val = match (sub_opt.has, sub_val) {
+                (Arg::No, Some(_)) => {
+                    return Err(mkerr(format!("unexpected sub-option argument: {item}")));
+                }
+                (Arg::Yes, None) => {
+                    return Err(mkerr(format!("missing sub-option argument: {key}")));
+                }
+                (Arg::Maybe, None) => Some(&item[item.len()..]),
+                (Arg::MaybeNext, None) => Some(&item[item.len()..]),
+                (_, sub_val) => sub_val,
+            };
+            v_subs.push(NameVal {
+                opt: sub_opt,
+                val: sub_val,
+                #[cfg(feature = "was_long")]
+                was_long: true,
+                #[cfg(feature = "stream_spec")]
+                spec: None,
+                #[cfg(feature = "expand")]
+                implied: false,
+                #[cfg(feature = "secondary_prefix")]
+                prefix: self.prefix,
+            });
+        }
+        Ok(v_subs)
+    }
+}
+
+// the error is the hint of invalid option argument
+#[cfg(any(feature = "subopt", feature = "dox"))]
+fn find_subopt<'a>(sub_ary: &'a [Opt<'a>], key: &str) -> Result<&'a Opt<'a>, String> {
+    if let Ok(idx) = sub_ary.binary_search_by_key(&key, |&o| o.lon) {
+        return Ok(&sub_ary[idx]);
+    }
+    #[cfg(feature = "abbreviate")]
+    {
+        let ambiguous = dedup_aliases(prefix_range(sub_ary, key, |o| o.lon));
+        if ambiguous.len() == 1 {
+            return Ok(ambiguous[0]);
+        }
+        if ambiguous.len() > 1 {
+            let mut hint = format!("ambiguous sub-option: {key}: possibilities:");
+            for a in ambiguous {
+                hint.push_str(&format!(" '{}'", a.lon));
+            }
+            return Err(hint);
+        }
+    }
+    Err(format!("invalid sub-option: {key}"))
+}
+
 /// Tokens as the result of lex
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct Tokens<'a> {
     pub namevals: Vec<NameVal<'a>>,
     #[cfg(any(feature = "stop_at_mm", feature = "dox"))]
@@ -321,6 +722,268 @@ pub struct Tokens<'a> {
     #[cfg(any(feature = "subcommand", feature = "dox"))]
     pub subcmd: Option<&'a str>,
     pub free: Vec<&'a str>,
+    /// index of `free` of the first argument after `--`, that ended the options.
+    ///
+    /// This is `None` with `--` after the free argument, that ended the options
+    /// with `stop_at_free` or `subcommand`.
+    ///
+    /// See [`Tokens::before_mm()`] and [`Tokens::after_mm()`].
+    #[cfg(any(feature = "passthrough", feature = "dox"))]
+    pub mm_index: Option<usize>,
+    /// unknown options in order with [`Lex::pass_unknown()`], that can be passed
+    /// to the other program.
+    ///
+    /// The long option is the argument as it is, like `--foo=bar`.
+    /// The short option is a letter with `-`, like `-x` of `-axb`.
+    /// The separated argument of the unknown option is in `free`,
+    /// because it can not be known.
+    #[cfg(any(feature = "pass_unknown", feature = "dox"))]
+    pub unknown: Vec<Cow<'a, str>>,
+    /// all the above in the order of the command line
+    #[cfg(any(feature = "events", feature = "dox"))]
+    pub events: Vec<Event<'a>>,
+    /// non-fatal warnings, like the deprecated options
+    #[cfg(any(feature = "warnings", feature = "dox"))]
+    pub warnings: Vec<OptParseWarning>,
+}
+
+/// Tokens and the errors, as the result of [`Lex::tokens_partial_from()`]
+#[cfg(any(not(feature = "single_error"), feature = "dox"))]
+#[derive(Debug)]
+pub struct PartialTokens<'a> {
+    /// the options and the arguments, that are lexed successfully
+    pub tokens: Tokens<'a>,
+    /// the errors, that are empty if no error
+    pub errs: OptParseErrors,
+}
+
+#[cfg(any(not(feature = "single_error"), feature = "dox"))]
+impl<'a> PartialTokens<'a> {
+    /// the tokens without the errors, or the errors, like [`Lex::tokens_from()`]
+    pub fn into_result(self) -> Result<Tokens<'a>, OptParseErrors> {
+        if self.errs.is_empty() {
+            Ok(self.tokens)
+        } else {
+            Err(self.errs)
+        }
+    }
+}
+
+/// Event of [`Tokens::events`], in the order of the command line
+#[cfg(any(feature = "events", feature = "dox"))]
+#[derive(Debug, Clone)]
+pub enum Event<'a> {
+    /// option, the same as in `namevals`
+    NameVal(NameVal<'a>),
+    /// free argument, the same as in `free`
+    Free(&'a str),
+    /// `--` that stopped the option parsing
+    #[cfg(any(feature = "stop_at_mm", feature = "dox"))]
+    DoubleM,
+    /// subcommand
+    #[cfg(any(feature = "subcommand", feature = "dox"))]
+    SubCmd(&'a str),
+    /// unknown option, the same as in `unknown`
+    #[cfg(any(feature = "pass_unknown", feature = "dox"))]
+    Unknown(Cow<'a, str>),
+}
+
+#[cfg(any(feature = "passthrough", feature = "dox"))]
+impl<'a> Tokens<'a> {
+    /// the operands before `--`, that ended the options
+    ///
+    /// These are all of `free` without `--`, if no `--` ended the options.
+    /// With `stop_at_free` or `subcommand`, the options end at the first
+    /// free argument, and `--` after it is in this as it is, like
+    /// `["build", "-x", "--", "y"]` of `-v build -x -- y`.
+    pub fn before_mm(&self) -> &[&'a str] {
+        match self.mm_index {
+            // `free[idx - 1]` is `--`, if it was not the first
+            Some(idx) => &self.free[..idx.saturating_sub(1)],
+            None => &self.free,
+        }
+    }
+    /// the arguments after `--`, that ended the options
+    pub fn after_mm(&self) -> &[&'a str] {
+        match self.mm_index {
+            Some(idx) => &self.free[idx..],
+            None => &[],
+        }
+    }
+    /// the trailing arguments, `before_mm()` and then `after_mm()`.
+    ///
+    /// These are all the arguments after the own options, without `--`
+    /// that ended the options. With `stop_at_free` or `subcommand`,
+    /// they are forwarded untouched, like the arguments of the subcommand
+    /// or of the other program.
+    ///
+    /// # Examples
+    /// ```
+    /// #[cfg(all(feature = "passthrough", feature = "subcommand"))]
+    /// #[cfg(not(feature = "long_only"))]
+    /// {
+    ///     use flood_tide::{Arg, Lex, Opt};
+    ///
+    ///     #[rustfmt::skip]
+    ///     const OPT_ARY: [Opt;1] = [
+    ///         Opt { sho: b'v', lon: "verbose", has: Arg::No, num: 1, },
+    ///     ];
+    ///     const OPT_ARY_SHO_IDX: [(u8,usize);1] = [(b'v',0)];
+    ///
+    ///     const SUBCMD_ARY: [&str;2] = ["exec", "run"];
+    ///
+    ///     let lex = Lex::create_with(&OPT_ARY, &OPT_ARY_SHO_IDX).subcmd(&SUBCMD_ARY);
+    ///     let args = ["-v", "exec", "-v", "--", "ls", "-l"];
+    ///     let tokens = lex.tokens_from(&args).unwrap();
+    ///     assert_eq!(tokens.subcmd, Some("exec"));
+    ///     assert_eq!(tokens.trailing(), ["-v", "--", "ls", "-l"]);
+    ///     //
+    ///     // the options of the subcommand, and its trailing arguments
+    ///     let lex = lex.subcmd(&[]);
+    ///     let tokens = lex.tokens_from(&args[2..]).unwrap();
+    ///     assert_eq!(tokens.namevals.len(), 1);
+    ///     assert_eq!(tokens.free, ["ls", "-l"]);
+    ///     assert_eq!(tokens.after_mm(), ["ls", "-l"]);
+    ///     assert_eq!(tokens.trailing(), ["ls", "-l"]);
+    /// }
+    /// ```
+    pub fn trailing(&self) -> Vec<&'a str> {
+        let mut v = self.before_mm().to_vec();
+        v.extend_from_slice(self.after_mm());
+        v
+    }
+}
+
+/// Group of options delimited by an anchor, as the result of [`Tokens::groups()`]
+#[cfg(any(feature = "groups", feature = "dox"))]
+#[derive(Debug, Clone)]
+pub struct Group<'a> {
+    /// options before the anchor
+    pub namevals: Vec<NameVal<'a>>,
+    /// the anchor, `None` is the trailing options without the anchor
+    pub anchor: Option<Anchor<'a>>,
+}
+
+/// Anchor of [`Group`]
+#[cfg(any(feature = "groups", feature = "dox"))]
+#[derive(Debug, Clone)]
+pub enum Anchor<'a> {
+    /// anchor option. ex) `-i infile`
+    NameVal(NameVal<'a>),
+    /// free argument. ex) `outfile`
+    Free(&'a str),
+}
+
+#[cfg(any(feature = "groups", feature = "dox"))]
+impl<'a> Tokens<'a> {
+    /// split the options into the groups delimited by the anchors.
+    ///
+    /// The anchors are the options of `anchor_nums` and the free arguments.
+    /// Each group has the options placed before its anchor, like
+    /// `ffmpeg [[infile options] -i infile]... {[outfile options] outfile}...`.
+    /// The subcommand and the unknown options are not in the groups.
+    ///
+    /// # Examples
+    /// ```
+    /// #[cfg(all(feature = "groups", feature = "option_argument"))]
+    /// {
+    ///     use flood_tide::{Anchor, Arg, Lex, Opt};
+    ///
+    ///     #[rustfmt::skip]
+    ///     const OPT_ARY: [Opt;3] = [
+    ///         Opt { sho: b'c', lon: "codec", has: Arg::Yes, num: 1, },
+    ///         Opt { sho: b'i', lon: "input", has: Arg::Yes, num: 2, },
+    ///         Opt { sho: b'y', lon: "yes",   has: Arg::No,  num: 3, },
+    ///     ];
+    ///     const OPT_ARY_SHO_IDX: [(u8,usize);3] = [(b'c',0),(b'i',1),(b'y',2)];
+    ///
+    ///     let args = ["-y", "-c", "h264", "-i", "in.mp4", "-c", "copy", "out.mp4"];
+    ///     let lex = Lex::create_with(&OPT_ARY, &OPT_ARY_SHO_IDX);
+    ///     let tokens = lex.tokens_from(&args).unwrap();
+    ///     let groups = tokens.groups(&[2]);
+    ///     assert_eq!(groups.len(), 2);
+    ///     // `-y -c h264 -i in.mp4`
+    ///     assert_eq!(groups[0].namevals.len(), 2);
+    ///     assert!(matches!(&groups[0].anchor, Some(Anchor::NameVal(nv)) if nv.val == Some("in.mp4")));
+    ///     // `-c copy out.mp4`
+    ///     assert_eq!(groups[1].namevals[0].val, Some("copy"));
+    ///     assert!(matches!(groups[1].anchor, Some(Anchor::Free("out.mp4"))));
+    /// }
+    /// ```
+    pub fn groups(&self, anchor_nums: &[OptNum]) -> Vec<Group<'a>> {
+        let mut groups = Vec::new();
+        let mut namevals = Vec::new();
+        for ev in self.events.iter() {
+            #[allow(unreachable_patterns)]
+            let anchor = match ev {
+                Event::NameVal(nv) if anchor_nums.contains(&nv.opt.num) => {
+                    Anchor::NameVal(nv.clone())
+                }
+                Event::NameVal(nv) => {
+                    namevals.push(nv.clone());
+                    continue;
+                }
+                Event::Free(s) => Anchor::Free(s),
+                _ => continue,
+            };
+            groups.push(Group {
+                namevals: core::mem::take(&mut namevals),
+                anchor: Some(anchor),
+            });
+        }
+        if !namevals.is_empty() {
+            groups.push(Group {
+                namevals,
+                anchor: None,
+            });
+        }
+        groups
+    }
+}
+
+// the events are built from the growth of the vectors at each argument
+#[cfg(feature = "events")]
+#[derive(Default)]
+struct EventsBuilder<'a> {
+    events: Vec<Event<'a>>,
+    nv_len: usize,
+    free_len: usize,
+    #[cfg(feature = "pass_unknown")]
+    unknown_len: usize,
+}
+
+#[cfg(feature = "events")]
+impl<'a> EventsBuilder<'a> {
+    fn flush(
+        &mut self,
+        namevals: &[NameVal<'a>],
+        free: &[&'a str],
+        #[cfg(feature = "pass_unknown")] unknown: &[Cow<'a, str>],
+    ) {
+        self.events
+            .extend(namevals[self.nv_len..].iter().cloned().map(Event::NameVal));
+        self.nv_len = namevals.len();
+        #[cfg(feature = "pass_unknown")]
+        {
+            self.events.extend(
+                unknown[self.unknown_len..]
+                    .iter()
+                    .cloned()
+                    .map(Event::Unknown),
+            );
+            self.unknown_len = unknown.len();
+        }
+        self.events
+            .extend(free[self.free_len..].iter().map(|&s| Event::Free(s)));
+        self.free_len = free.len();
+    }
+    // replace the first free argument, that is removed from `free`
+    #[cfg(any(feature = "stop_at_mm", feature = "subcommand"))]
+    fn replace_first_free(&mut self, ev: Event<'a>) {
+        if let Some(x) = self.events.iter_mut().find(|e| matches!(e, Event::Free(_))) {
+            *x = ev;
+        }
+    }
 }
 
 /// Lexical analyzer
@@ -365,25 +1028,332 @@ pub struct Lex<'a> {
     sho_idx: &'a [(u8, usize)],
     #[cfg(any(feature = "subcommand", feature = "dox"))]
     subcmds: &'a [&'a str],
+    #[cfg(any(all(feature = "subcommand", feature = "abbreviate"), feature = "dox"))]
+    subcmds_sorted: bool,
+    #[cfg(any(feature = "stream_spec", feature = "dox"))]
+    stream_specs: &'a [OptNum],
+    #[cfg(any(feature = "expand", feature = "dox"))]
+    expands: &'a [Expand<'a>],
+    #[cfg(any(feature = "warnings", feature = "dox"))]
+    deprecateds: &'a [(&'a str, &'a str)],
+    #[cfg(any(feature = "secondary_prefix", feature = "dox"))]
+    sec_prefix: u8,
+    #[cfg(any(feature = "numeric", feature = "dox"))]
+    negative_number: bool,
+    #[cfg(any(feature = "numeric", feature = "dox"))]
+    numeric_shorthand: Option<OptNum>,
+    #[cfg(any(feature = "strict_value", feature = "dox"))]
+    strict_value: Option<StrictValue<'a>>,
+    #[cfg(any(feature = "value_policy", feature = "dox"))]
+    value_policy: ValuePolicy,
+    #[cfg(any(feature = "long_escape", feature = "dox"))]
+    long_escape: u8,
+    #[cfg(any(feature = "help_version", feature = "dox"))]
+    help_version: &'a [OptNum],
+    #[cfg(any(feature = "warnings", feature = "dox"))]
+    single_values: &'a [OptNum],
+    #[cfg(any(all(feature = "warnings", feature = "abbreviate"), feature = "dox"))]
+    abbreviation_warning: bool,
+    #[cfg(any(feature = "occurrence", feature = "dox"))]
+    occurrences: &'a [(OptNum, Occurrence)],
+    #[cfg(any(feature = "pass_unknown", feature = "dox"))]
+    pass_unknown: bool,
+    #[cfg(any(feature = "ignore_case", feature = "dox"))]
+    ignore_case: bool,
 }
 
 impl<'a> Lex<'a> {
     /// create lexical analyzer
+    ///
+    /// At the debug build, this panics if the tables have
+    /// any [`check::Diagnostic`] of [`check::Severity::Error`].
     pub fn create_with(opt_ary: &'a [Opt], sho_idx_ary: &'a [(u8, usize)]) -> Lex<'a> {
+        #[cfg(debug_assertions)]
+        {
+            let diags = check::validate(opt_ary, sho_idx_ary);
+            let mut errs = diags
+                .iter()
+                .filter(|diag| diag.severity() == check::Severity::Error);
+            if let Some(diag) = errs.next() {
+                panic!("invalid opt tables: {} ({} errors)", diag, errs.count() + 1);
+            }
+        }
         Lex {
             opts: opt_ary,
             sho_idx: sho_idx_ary,
             #[cfg(feature = "subcommand")]
             subcmds: &[],
+            #[cfg(all(feature = "subcommand", feature = "abbreviate"))]
+            subcmds_sorted: true,
+            #[cfg(feature = "stream_spec")]
+            stream_specs: &[],
+            #[cfg(feature = "expand")]
+            expands: &[],
+            #[cfg(feature = "warnings")]
+            deprecateds: &[],
+            #[cfg(feature = "secondary_prefix")]
+            sec_prefix: 0,
+            #[cfg(feature = "numeric")]
+            negative_number: false,
+            #[cfg(feature = "numeric")]
+            numeric_shorthand: None,
+            #[cfg(feature = "strict_value")]
+            strict_value: None,
+            #[cfg(feature = "value_policy")]
+            value_policy: ValuePolicy::default(),
+            #[cfg(feature = "long_escape")]
+            long_escape: 0,
+            #[cfg(feature = "help_version")]
+            help_version: &[],
+            #[cfg(feature = "warnings")]
+            single_values: &[],
+            #[cfg(all(feature = "warnings", feature = "abbreviate"))]
+            abbreviation_warning: false,
+            #[cfg(feature = "occurrence")]
+            occurrences: &[],
+            #[cfg(feature = "pass_unknown")]
+            pass_unknown: false,
+            #[cfg(feature = "ignore_case")]
+            ignore_case: false,
         }
     }
     /// setup subcommand ary
+    ///
+    /// The `subcmd_ary` may be unsorted. With feature = "abbreviate",
+    /// the sorted one is searched faster by the binary search.
     #[cfg(any(feature = "subcommand", feature = "dox"))]
     #[inline]
     pub fn subcmd(mut self, subcmd_ary: &'a [&'a str]) -> Self {
         self.subcmds = subcmd_ary;
+        #[cfg(feature = "abbreviate")]
+        {
+            self.subcmds_sorted = subcmd_ary.windows(2).all(|w| w[0] < w[1]);
+        }
+        self
+    }
+    /// setup option nums accepting the stream specifier
+    ///
+    /// The options of `spec_nums` accept the `:specifier` suffix after
+    /// the name, like `-c:v` of long only or `--codec:v=copy`.
+    /// The specifier is in [`NameVal::spec`].
+    #[cfg(any(feature = "stream_spec", feature = "dox"))]
+    #[inline]
+    pub fn stream_spec(mut self, spec_nums: &'a [OptNum]) -> Self {
+        self.stream_specs = spec_nums;
+        self
+    }
+    /// setup expand ary
+    ///
+    /// The option of the `expand_ary` is followed by the expanded options,
+    /// that are marked as [`NameVal::implied`]. The expansion is not recursive.
+    /// At the debug build, this panics if the `expand_ary` is not sorted or
+    /// has the `OptNum` that is not in the opt ary.
+    #[cfg(any(feature = "expand", feature = "dox"))]
+    pub fn expand(mut self, expand_ary: &'a [Expand<'a>]) -> Self {
+        #[cfg(debug_assertions)]
+        {
+            if !expand_ary.windows(2).all(|w| w[0].0 < w[1].0) {
+                panic!("invalid expand table: not sorted");
+            }
+            for &(num, to) in expand_ary {
+                for &(to_num, _) in to {
+                    if !self.opts.iter().any(|o| o.num == to_num) {
+                        panic!("invalid expand table: {}: unknown num: {}", num, to_num);
+                    }
+                }
+            }
+        }
+        self.expands = expand_ary;
+        self
+    }
+    /// setup deprecated ary
+    ///
+    /// The `deprecated_ary` is the pairs of the option name and the hint,
+    /// like `("--colour", "use --color")`. The name is spelled as on
+    /// the command line, `--name` of the long name or `-x` of the short name,
+    /// so the short name of the option with the long name can be deprecated
+    /// alone. With `long_only`, the long name is `-name`.
+    /// The secondary prefix is spelled with `-`, like `--name` of `++name`.
+    /// The deprecated option is parsed, and [`OptParseWarningKind::DeprecatedOption`]
+    /// is in [`Tokens::warnings`].
+    #[cfg(any(feature = "warnings", feature = "dox"))]
+    #[inline]
+    pub fn deprecated(mut self, deprecated_ary: &'a [(&'a str, &'a str)]) -> Self {
+        self.deprecateds = deprecated_ary;
+        self
+    }
+    /// setup the secondary prefix character
+    ///
+    /// The arguments starting with `sec_prefix`, like `+x`, are parsed
+    /// by the same rules and the same opt ary as `-`: `+xyz` is
+    /// the combined short names, `++name` is the long name, and `+name` is
+    /// the long name of long only. The prefix is in [`NameVal::prefix`].
+    /// The lone `+` is a free argument.
+    /// At the debug build, this panics if `sec_prefix` is `-` or not
+    /// an ASCII punctuation.
+    #[cfg(any(feature = "secondary_prefix", feature = "dox"))]
+    pub fn secondary_prefix(mut self, sec_prefix: u8) -> Self {
+        #[cfg(debug_assertions)]
+        if sec_prefix == b'-' || !sec_prefix.is_ascii_punctuation() {
+            panic!("invalid secondary prefix: {:?}", sec_prefix as char);
+        }
+        self.sec_prefix = sec_prefix;
+        self
+    }
+    /// setup the negative numbers as the free arguments
+    ///
+    /// The arguments like `-5`, `-1.5` and `-.5e3` are not the options,
+    /// but the free arguments. The short options of digits are shadowed.
+    #[cfg(any(feature = "numeric", feature = "dox"))]
+    #[inline]
+    pub fn negative_number(mut self) -> Self {
+        self.negative_number = true;
+        self
+    }
+    /// setup the numeric shorthand option
+    ///
+    /// The argument of `-<digits>` is the option of `num` with
+    /// the argument of the digits, like `head -20` ::= `head -n 20`.
+    /// This takes precedence over [`Lex::negative_number()`].
+    /// At the debug build, this panics if `num` is not in the opt ary.
+    #[cfg(any(feature = "numeric", feature = "dox"))]
+    pub fn numeric_shorthand(mut self, num: OptNum) -> Self {
+        #[cfg(debug_assertions)]
+        if !self.opts.iter().any(|o| o.num == num) {
+            panic!("invalid numeric shorthand: unknown num: {}", num);
+        }
+        self.numeric_shorthand = Some(num);
+        self
+    }
+    /// setup the strict value policy
+    ///
+    /// The next argument starting with `-`, like `--verbose` of
+    /// `--output --verbose`, is not the option argument of the options
+    /// of the policy, and it is [`OptParseErrorKind::MissingOptionArgument`].
+    /// The argument attached to the option, like `--output=-x`, is not checked.
+    #[cfg(any(feature = "strict_value", feature = "dox"))]
+    #[inline]
+    pub fn strict_value(mut self, policy: StrictValue<'a>) -> Self {
+        self.strict_value = Some(policy);
+        self
+    }
+    /// setup the value policy
+    ///
+    /// The violation of the policy is the error, like
+    /// [`OptParseErrorKind::MissingOptionArgument`] of `--name value`,
+    /// [`OptParseErrorKind::UnexpectedOptionArgument`] of `-ovalue` and
+    /// [`OptParseErrorKind::InvalidOption`] of `-abc`.
+    #[cfg(any(feature = "value_policy", feature = "dox"))]
+    #[inline]
+    pub fn value_policy(mut self, policy: ValuePolicy) -> Self {
+        self.value_policy = policy;
+        self
+    }
+    /// setup the short name escaping to the long name
+    ///
+    /// The short name `long_escape` is followed by the long name,
+    /// like `-W foo` ::= `--foo` and `-Wfoo=bar` ::= `--foo=bar` of GNU `getopt_long`.
+    /// The long name is abbreviated at the compiling with feature = "abbreviate".
+    #[cfg(any(feature = "long_escape", feature = "dox"))]
+    #[inline]
+    pub fn long_escape(mut self, long_escape: u8) -> Self {
+        self.long_escape = long_escape;
+        self
+    }
+    /// setup option nums of the help and the version
+    ///
+    /// These options win over the other errors, like GNU tools.
+    /// If one of them is in the arguments, the errors are ignored and
+    /// the tokens are returned, like `prog --bogus --help` is the help.
+    #[cfg(any(feature = "help_version", feature = "dox"))]
+    #[inline]
+    pub fn help_version(mut self, nums: &'a [OptNum]) -> Self {
+        self.help_version = nums;
+        self
+    }
+    /// setup option nums of the single value
+    ///
+    /// The repeated option of `nums` overrides the previous one, and
+    /// [`OptParseWarningKind::OverriddenOption`] is in [`Tokens::warnings`],
+    /// like `--output a --output b`.
+    #[cfg(any(feature = "warnings", feature = "dox"))]
+    #[inline]
+    pub fn single_value(mut self, nums: &'a [OptNum]) -> Self {
+        self.single_values = nums;
         self
     }
+    /// setup the warning of the abbreviated long name
+    ///
+    /// The abbreviation may become ambiguous with a new option of the later version.
+    /// [`OptParseWarningKind::AbbreviatedOption`] is in [`Tokens::warnings`],
+    /// like `--verb` of `--verbose`.
+    #[cfg(any(all(feature = "warnings", feature = "abbreviate"), feature = "dox"))]
+    #[inline]
+    pub fn abbreviation_warning(mut self) -> Self {
+        self.abbreviation_warning = true;
+        self
+    }
+    /// setup the occurrence policies of the repeated options
+    ///
+    /// The `occurrence_ary` is the pairs of the option num and [`Occurrence`].
+    /// The option without the policy is [`Occurrence::Accumulate`].
+    /// The implied options of [`Lex::expand()`] are not the occurrences.
+    #[cfg(any(feature = "occurrence", feature = "dox"))]
+    #[inline]
+    pub fn occurrence(mut self, occurrence_ary: &'a [(OptNum, Occurrence)]) -> Self {
+        self.occurrences = occurrence_ary;
+        self
+    }
+    /// setup the pass through of the unknown options
+    ///
+    /// The unknown options are collected into [`Tokens::unknown`] in order,
+    /// instead of [`OptParseErrorKind::InvalidOption`], to pass them to
+    /// the other program. Without this, the unknown options are the errors.
+    #[cfg(any(feature = "pass_unknown", feature = "dox"))]
+    #[inline]
+    pub fn pass_unknown(mut self) -> Self {
+        self.pass_unknown = true;
+        self
+    }
+    /// setup the case insensitive match
+    ///
+    /// The long options and the subcommands that are not found are matched
+    /// ASCII case-insensitively, the short options are not.
+    #[cfg(any(feature = "ignore_case", feature = "dox"))]
+    #[inline]
+    pub fn ignore_case(mut self) -> Self {
+        self.ignore_case = true;
+        self
+    }
+    #[cfg(feature = "help_version")]
+    #[inline]
+    fn has_help_version(&self, namevals: &[NameVal]) -> bool {
+        namevals
+            .iter()
+            .any(|nv| self.help_version.contains(&nv.opt.num))
+    }
+
+    // the option keyword, the lone prefix is not
+    #[inline]
+    fn is_prefixed(&self, cur: &str) -> bool {
+        #[cfg(feature = "secondary_prefix")]
+        if self.sec_prefix != 0 && cur.len() > 1 && cur.as_bytes()[0] == self.sec_prefix {
+            // the lone `++` is not
+            return cur.len() > 2 || cur.as_bytes()[1] != self.sec_prefix;
+        }
+        cur.len() > 1 && cur.starts_with('-')
+    }
+
+    #[cfg(feature = "numeric")]
+    #[inline]
+    fn is_negative_number(&self, cur: &str) -> bool {
+        self.negative_number && is_negative_number(cur)
+    }
+    #[cfg(not(feature = "numeric"))]
+    #[inline]
+    fn is_negative_number(&self, _: &str) -> bool {
+        false
+    }
 
     #[cfg(feature = "stop_at_mm")]
     #[inline]
@@ -422,9 +1392,19 @@ impl<'a> Lex<'a> {
     fn push_err(
         &self,
         #[cfg(not(feature = "single_error"))] v_errs: &mut OpErr,
+        #[cfg(all(feature = "single_error", feature = "help_version"))] v_errs: &mut Option<OpErr>,
         err: OptParseError,
     ) -> Result<(), OpErr> {
-        #[cfg(feature = "single_error")]
+        // the first error is deferred, the help or the version may follow
+        #[cfg(all(feature = "single_error", feature = "help_version"))]
+        {
+            if self.help_version.is_empty() {
+                return Err(err);
+            }
+            v_errs.get_or_insert(err);
+            Ok(())
+        }
+        #[cfg(all(feature = "single_error", not(feature = "help_version")))]
         {
             Err(err)
         }
@@ -439,11 +1419,16 @@ impl<'a> Lex<'a> {
     fn append_errs(
         &self,
         #[cfg(not(feature = "single_error"))] v_errs: &mut OpErr,
+        #[cfg(all(feature = "single_error", feature = "help_version"))] v_errs: &mut Option<OpErr>,
         errs: OpErr,
     ) -> Result<(), OpErr> {
         #[cfg(feature = "single_error")]
         {
-            Err(errs)
+            self.push_err(
+                #[cfg(feature = "help_version")]
+                v_errs,
+                errs,
+            )
         }
         #[cfg(not(feature = "single_error"))]
         {
@@ -472,20 +1457,109 @@ impl<'a> Lex<'a> {
 
     /// analyze and return tokens
     pub fn tokens_from(&'a self, args: &'a [&'a str]) -> Result<Tokens<'a>, OpErr> {
+        #[cfg(feature = "single_error")]
+        {
+            self.lex_tokens(args)
+        }
         #[cfg(not(feature = "single_error"))]
-        let mut v_errs = OpErr::new();
+        {
+            self.tokens_partial_from(args).into_result()
+        }
+    }
+
+    /// analyze and return the tokens with the errors.
+    ///
+    /// The tokens have the options and the free arguments, that are lexed
+    /// successfully, even if there are errors.
+    ///
+    /// # Examples
+    /// ```
+    /// #[cfg(not(feature = "single_error"))]
+    /// #[cfg(feature = "option_argument")]
+    /// #[cfg(not(feature = "long_only"))]
+    /// {
+    ///     use flood_tide::{Arg, Lex, Opt};
+    ///
+    ///     #[rustfmt::skip]
+    ///     const OPT_ARY: [Opt;2] = [
+    ///         Opt { sho: b'q', lon: "quiet",   has: Arg::No, num: 1, },
+    ///         Opt { sho: b'v', lon: "verbose", has: Arg::No, num: 2, },
+    ///     ];
+    ///     const OPT_ARY_SHO_IDX: [(u8,usize);2] = [(b'q',0),(b'v',1)];
+    ///
+    ///     let lex = Lex::create_with(&OPT_ARY, &OPT_ARY_SHO_IDX);
+    ///     let args = ["--quiet", "--bogus", "file"];
+    ///     let partial = lex.tokens_partial_from(&args);
+    ///     assert_eq!(partial.errs.len(), 1);
+    ///     assert_eq!(partial.tokens.namevals.len(), 1);
+    ///     assert_eq!(partial.tokens.namevals[0].opt.lon, "quiet");
+    ///     assert_eq!(partial.tokens.free, ["file"]);
+    ///     assert!(partial.into_result().is_err());
+    /// }
+    /// ```
+    #[cfg(any(not(feature = "single_error"), feature = "dox"))]
+    pub fn tokens_partial_from(&'a self, args: &'a [&'a str]) -> PartialTokens<'a> {
+        let mut errs = OpErr::new();
+        // the errors are collected into `errs`, and not returned
+        let tokens = match self.lex_tokens(args, &mut errs) {
+            Ok(tokens) => tokens,
+            Err(other) => {
+                errs.append(other);
+                Tokens::default()
+            }
+        };
+        PartialTokens { tokens, errs }
+    }
+
+    fn lex_tokens(
+        &'a self,
+        args: &'a [&'a str],
+        #[cfg(not(feature = "single_error"))] v_errs: &mut OpErr,
+    ) -> Result<Tokens<'a>, OpErr> {
+        #[cfg(all(feature = "single_error", feature = "help_version"))]
+        let mut first_err: Option<OpErr> = None;
+        #[cfg(all(feature = "single_error", feature = "help_version"))]
+        let v_errs = &mut first_err;
         let mut v_free: Vec<&str> = Vec::new();
+        #[cfg(feature = "passthrough")]
+        let mut v_mm_index: Option<usize> = None;
         let mut v_namevals: Vec<NameVal> = Vec::new();
+        #[cfg(feature = "warnings")]
+        let mut v_warnings: Vec<OptParseWarning> = Vec::new();
+        #[cfg(feature = "pass_unknown")]
+        let mut v_unknown: Vec<Cow<'a, str>> = Vec::new();
+        #[cfg(feature = "events")]
+        let mut v_events = EventsBuilder::default();
+        #[cfg(feature = "expand")]
+        let mut expanded_len = 0;
         //
         let mut cursor = args.iter();
         'itr_cursor: while let Some(cur) = cursor.next() {
+            #[cfg(feature = "expand")]
+            self.expand_namevals(&mut v_namevals, &mut expanded_len);
+            #[cfg(feature = "events")]
+            v_events.flush(
+                &v_namevals,
+                &v_free,
+                #[cfg(feature = "pass_unknown")]
+                &v_unknown,
+            );
             if self.is_double_m(cur) {
                 v_free.push(cur);
+                #[cfg(feature = "passthrough")]
+                {
+                    v_mm_index = Some(v_free.len());
+                }
                 v_free.extend(cursor);
                 break 'itr_cursor;
             }
-            let f_single = if !cur.starts_with('-') {
-                // free
+            #[cfg(feature = "numeric")]
+            if let Some(nv) = self.parse_numeric_shorthand(cur) {
+                v_namevals.push(nv);
+                continue 'itr_cursor;
+            }
+            let f_single = if !self.is_prefixed(cur) || self.is_negative_number(cur) {
+                // free, the lone `-` is stdin or stdout
                 v_free.push(cur);
                 if self.is_stop_at_free() {
                     v_free.extend(cursor);
@@ -495,15 +1569,39 @@ impl<'a> Lex<'a> {
             } else {
                 true
             };
+            #[cfg(feature = "secondary_prefix")]
+            let sec_nv_len =
+                (f_single && cur.as_bytes()[0] == self.sec_prefix).then(|| v_namevals.len());
+            #[cfg(all(feature = "secondary_prefix", feature = "pass_unknown"))]
+            let sec_un_len = v_unknown.len();
 
-            let f_single = if f_single && !self.is_long_only() && cur.starts_with("--") {
-                // option: long name
+            let f_single = if f_single
+                && !self.is_long_only()
+                && cur.as_bytes()[1] == cur.as_bytes()[0]
+            {
+                // option: long name, `--name` or `++name`
+                #[cfg(not(feature = "stop_at_mm"))]
+                if cur.len() == 2 {
+                    // the lone `--` is not the empty long name
+                    continue 'itr_cursor;
+                }
                 match self.parse_long_name(&mut cursor, &cur[2..]) {
-                    Ok(nv) => v_namevals.push(nv),
+                    Ok(nv) => {
+                        #[cfg(all(feature = "warnings", feature = "abbreviate"))]
+                        if let Some(w) = self.abbreviated_warning(&cur[..2], &cur[2..], &nv) {
+                            v_warnings.push(w);
+                        }
+                        v_namevals.push(nv);
+                    }
                     Err(err) => {
+                        #[cfg(feature = "pass_unknown")]
+                        if self.pass_unknown && err.kind() == OptParseErrorKind::InvalidOption {
+                            v_unknown.push(Cow::Borrowed(*cur));
+                            continue 'itr_cursor;
+                        }
                         self.push_err(
-                            #[cfg(not(feature = "single_error"))]
-                            &mut v_errs,
+                            #[cfg(any(not(feature = "single_error"), feature = "help_version"))]
+                            v_errs,
                             err,
                         )?;
                     }
@@ -515,38 +1613,133 @@ impl<'a> Lex<'a> {
             if f_single {
                 // option: short name or long only
                 if !self.is_long_only() {
-                    let res = self.parse_short_name(&mut cursor, &cur[1..], &mut v_namevals);
+                    let res = self.parse_short_name(
+                        &mut cursor,
+                        &cur[1..],
+                        &mut v_namevals,
+                        #[cfg(feature = "pass_unknown")]
+                        &mut v_unknown,
+                    );
                     if let Err(errs) = res {
                         self.append_errs(
-                            #[cfg(not(feature = "single_error"))]
-                            &mut v_errs,
+                            #[cfg(any(not(feature = "single_error"), feature = "help_version"))]
+                            v_errs,
                             errs,
                         )?;
                     }
                 } else {
                     #[cfg(feature = "long_only")]
                     {
-                        let res = self.parse_long_only(&mut cursor, cur, &mut v_namevals);
+                        #[cfg(all(feature = "warnings", feature = "abbreviate"))]
+                        let nv_len = v_namevals.len();
+                        let res = self.parse_long_only(
+                            &mut cursor,
+                            cur,
+                            &mut v_namevals,
+                            #[cfg(feature = "pass_unknown")]
+                            &mut v_unknown,
+                        );
+                        // the long name, not the short name of `-f`
+                        #[cfg(all(feature = "warnings", feature = "abbreviate"))]
+                        if !(cur.len() == 2 && self.has_short_name(cur.as_bytes()[1])) {
+                            if let Some(nv) = v_namevals.get(nv_len) {
+                                if let Some(w) = self.abbreviated_warning(&cur[..1], &cur[1..], nv)
+                                {
+                                    v_warnings.push(w);
+                                }
+                            }
+                        }
                         if let Err(errs) = res {
                             self.append_errs(
-                                #[cfg(not(feature = "single_error"))]
-                                &mut v_errs,
+                                #[cfg(any(
+                                    not(feature = "single_error"),
+                                    feature = "help_version"
+                                ))]
+                                v_errs,
                                 errs,
                             )?;
                         }
                     }
                 }
             }
+            #[cfg(feature = "secondary_prefix")]
+            if let Some(nv_len) = sec_nv_len {
+                for nv in &mut v_namevals[nv_len..] {
+                    nv.prefix = self.sec_prefix;
+                }
+                // the owned unknowns are made with `-`, like `-x`
+                #[cfg(feature = "pass_unknown")]
+                for un in &mut v_unknown[sec_un_len..] {
+                    if let Cow::Owned(s) = un {
+                        let name = s.trim_start_matches('-');
+                        let prefix = (self.sec_prefix as char).to_string();
+                        *s = prefix.repeat(s.len() - name.len()) + name;
+                    }
+                }
+            }
         }
         //
+        #[cfg(feature = "help_version")]
+        let _is_help_version = self.has_help_version(&v_namevals);
+        #[cfg(not(feature = "help_version"))]
+        let _is_help_version = false;
         #[cfg(not(feature = "single_error"))]
-        {
-            if !v_errs.is_empty() {
-                return Err(v_errs);
+        if _is_help_version {
+            *v_errs = OpErr::new();
+        }
+        // the later errors are not added to the lex errors, for example,
+        // the invalid subcommand can be the argument of the invalid option
+        #[cfg(not(feature = "single_error"))]
+        #[cfg(any(feature = "occurrence", feature = "subcommand"))]
+        let is_lexed_ok = v_errs.is_empty();
+        #[cfg(all(feature = "single_error", feature = "help_version"))]
+        if let Some(err) = v_errs.take() {
+            if !_is_help_version {
+                return Err(err);
             }
         }
         //
+        #[cfg(feature = "expand")]
+        self.expand_namevals(&mut v_namevals, &mut expanded_len);
+        #[cfg(feature = "events")]
+        v_events.flush(
+            &v_namevals,
+            &v_free,
+            #[cfg(feature = "pass_unknown")]
+            &v_unknown,
+        );
+        #[cfg(feature = "warnings")]
+        self.namevals_warnings(&v_namevals, &mut v_warnings);
+        #[cfg(feature = "occurrence")]
+        {
+            let errs = self.apply_occurrence(
+                &mut v_namevals,
+                #[cfg(feature = "events")]
+                &mut v_events.events,
+            );
+            if !errs.is_empty() && !_is_help_version {
+                #[cfg(feature = "single_error")]
+                return Err(errs.into_iter().next().unwrap());
+                #[cfg(not(feature = "single_error"))]
+                if is_lexed_ok {
+                    for err in errs {
+                        v_errs.push(err);
+                    }
+                }
+            }
+        }
         let _is_stop_at_double_m = self.handle_double_m_removal(&mut v_free);
+        #[cfg(all(feature = "events", feature = "stop_at_mm"))]
+        if _is_stop_at_double_m {
+            v_events.replace_first_free(Event::DoubleM);
+        }
+        // the removed `--` was the first of `free`
+        #[cfg(feature = "passthrough")]
+        let v_mm_index = if _is_stop_at_double_m {
+            Some(0)
+        } else {
+            v_mm_index
+        };
         //
         #[cfg(feature = "subcommand")]
         {
@@ -554,11 +1747,17 @@ impl<'a> Lex<'a> {
             let b = !self.subcmds.is_empty() && !_is_stop_at_double_m;
             #[cfg(not(feature = "stop_at_mm"))]
             let b = !self.subcmds.is_empty();
+            // the invalid subcommand is ignored with the help or the version
+            let b = b && !(_is_help_version && self.parse_subcmd(&v_free).is_err());
             let v_cmd = if b {
                 match self.parse_subcmd(&v_free) {
                     Ok((opt, remove_1st)) => {
                         if remove_1st {
                             v_free.remove(0);
+                            #[cfg(feature = "events")]
+                            if let Some(subcmd) = opt {
+                                v_events.replace_first_free(Event::SubCmd(subcmd));
+                            }
                         }
                         opt
                     }
@@ -567,8 +1766,10 @@ impl<'a> Lex<'a> {
                         return Err(err);
                         #[cfg(not(feature = "single_error"))]
                         {
-                            v_errs.push(err);
-                            return Err(v_errs);
+                            if is_lexed_ok {
+                                v_errs.push(err);
+                            }
+                            None
                         }
                     }
                 }
@@ -578,9 +1779,17 @@ impl<'a> Lex<'a> {
             Ok(Tokens {
                 namevals: v_namevals,
                 free: v_free,
+                #[cfg(feature = "passthrough")]
+                mm_index: v_mm_index,
                 #[cfg(feature = "stop_at_mm")]
                 double_m: _is_stop_at_double_m,
                 subcmd: v_cmd,
+                #[cfg(feature = "pass_unknown")]
+                unknown: v_unknown,
+                #[cfg(feature = "events")]
+                events: v_events.events,
+                #[cfg(feature = "warnings")]
+                warnings: v_warnings,
             })
         }
         #[cfg(not(feature = "subcommand"))]
@@ -588,8 +1797,16 @@ impl<'a> Lex<'a> {
             Ok(Tokens {
                 namevals: v_namevals,
                 free: v_free,
+                #[cfg(feature = "passthrough")]
+                mm_index: v_mm_index,
                 #[cfg(feature = "stop_at_mm")]
                 double_m: _is_stop_at_double_m,
+                #[cfg(feature = "pass_unknown")]
+                unknown: v_unknown,
+                #[cfg(feature = "events")]
+                events: v_events.events,
+                #[cfg(feature = "warnings")]
+                warnings: v_warnings,
             })
         }
     }
@@ -600,25 +1817,43 @@ impl<'a> Lex<'a> {
     //
     #[cfg(feature = "abbreviate")]
     fn find_abbreviate(&'a self, name: &'a str) -> Result<&'a Opt<'a>, OptParseError> {
-        #[rustfmt::skip]
-        let ambiguous: Vec<&Opt<'a>> = self.opts.iter()
-            .filter(|&o| o.lon.starts_with(name)).collect();
+        let ambiguous = prefix_range(self.opts, name, |o| o.lon);
         match ambiguous.len() {
-            1 => Ok(ambiguous[0]),
+            1 => Ok(&ambiguous[0]),
             0 => mkerr_invalid_option(name),
-            _ => mkerr_ambiguous_option(name, &ambiguous),
+            _ if ambiguous[0].lon == name => Ok(&ambiguous[0]),
+            _ => match dedup_aliases(ambiguous) {
+                // the aliases of the same option
+                v if v.len() == 1 => Ok(v[0]),
+                v => mkerr_ambiguous_option(name, &v),
+            },
         }
     }
     //
     #[cfg(feature = "subcommand")]
     #[cfg(feature = "abbreviate")]
     fn find_abbreviate_subcmd<'b>(&'a self, name: &'b str) -> Result<&'a str, OptParseError> {
-        #[rustfmt::skip]
-        let ambiguous: Vec<&'a str> = self.subcmds.iter()
-            .filter(|&o| o.starts_with(name)).copied().collect();
+        #[cfg(feature = "ignore_case")]
+        if self.ignore_case && !self.subcmds.contains(&name) {
+            return self.find_ignore_case_subcmd(name);
+        }
+        let ambiguous: Vec<&'a str> = if self.subcmds_sorted {
+            prefix_range(self.subcmds, name, |&o| o).to_vec()
+        } else {
+            // the unsorted ary, the exact match is the first after sorting
+            let mut v: Vec<&'a str> = self
+                .subcmds
+                .iter()
+                .copied()
+                .filter(|o| o.starts_with(name))
+                .collect();
+            v.sort_unstable();
+            v
+        };
         match ambiguous.len() {
             1 => Ok(ambiguous[0]),
             0 => mkerr_invalid_subcommand(name),
+            _ if ambiguous[0] == name => Ok(ambiguous[0]),
             _ => mkerr_ambiguous_subcommand(name, &ambiguous),
         }
     }
@@ -626,18 +1861,40 @@ impl<'a> Lex<'a> {
     #[cfg(feature = "subcommand")]
     #[cfg(not(feature = "abbreviate"))]
     fn find_match_subcmd<'b>(&'a self, name: &'b str) -> Result<&'a str, OptParseError> {
-        #[rustfmt::skip]
-        let ambiguous: Vec<&'a str> = self.subcmds.iter()
-            .filter(|&o| o == &name).copied().collect();
-        match ambiguous.len() {
-            1 => Ok(ambiguous[0]),
-            _ => mkerr_invalid_subcommand(name),
+        match self.subcmds.iter().find(|&&o| o == name) {
+            Some(&subcmd) => Ok(subcmd),
+            #[cfg(feature = "ignore_case")]
+            None if self.ignore_case => self.find_ignore_case_subcmd(name),
+            None => mkerr_invalid_subcommand(name),
+        }
+    }
+    //
+    #[cfg(feature = "subcommand")]
+    #[cfg(feature = "ignore_case")]
+    fn find_ignore_case_subcmd<'b>(&'a self, name: &'b str) -> Result<&'a str, OptParseError> {
+        let subcmds = self.subcmds.iter().copied();
+        let exact: Vec<&str> = subcmds
+            .clone()
+            .filter(|s| s.eq_ignore_ascii_case(name))
+            .collect();
+        #[cfg(feature = "abbreviate")]
+        let exact = if exact.is_empty() {
+            subcmds
+                .filter(|s| starts_with_ignore_case(s, name))
+                .collect()
+        } else {
+            exact
+        };
+        match exact.len() {
+            1 => Ok(exact[0]),
+            0 => mkerr_invalid_subcommand(name),
+            _ => mkerr_ambiguous_subcommand(name, &exact),
         }
     }
     //
     fn parse_long_name(
         &'a self,
-        _cursor: &mut dyn Iterator<Item = &&'a str>,
+        _cursor: &mut Cursor<'a>,
         tail: &'a str,
     ) -> Result<NameVal<'a>, OptParseError> {
         #[cfg(feature = "option_argument")]
@@ -650,21 +1907,23 @@ impl<'a> Lex<'a> {
         };
         #[cfg(not(feature = "option_argument"))]
         let name = tail;
+        // `--=val` has no name
+        if name.is_empty() {
+            return mkerr_invalid_option(tail);
+        }
         //
-        let v_opt = {
-            let found = self.opts.binary_search_by_key(&name, |&o| o.lon);
-            match found {
-                Ok(idx) => &self.opts[idx],
-                _ => {
-                    #[cfg(feature = "abbreviate")]
-                    {
-                        self.find_abbreviate(name)?
-                    }
-                    #[cfg(not(feature = "abbreviate"))]
-                    return mkerr_invalid_option(name);
-                }
-            }
+        #[cfg(feature = "stream_spec")]
+        let (name, v_opt, spec) = match self.find_stream_spec(name) {
+            Some((base, opt, spec)) => (base, opt, Some(spec)),
+            None => (name, self.find_long_name(name)?, None),
         };
+        #[cfg(all(
+            feature = "stream_spec",
+            not(any(feature = "option_argument", feature = "was_long"))
+        ))]
+        let _ = name;
+        #[cfg(not(feature = "stream_spec"))]
+        let v_opt = self.find_long_name(name)?;
         //
         #[cfg(feature = "option_argument")]
         let val2 = match v_opt.has {
@@ -682,8 +1941,12 @@ impl<'a> Lex<'a> {
                 }
             }
             Arg::Yes => {
+                #[cfg(feature = "value_policy")]
+                if val.is_none() && self.value_policy.long_equals_only {
+                    return mkerr_missing_option_argument(name);
+                }
                 if val.is_none() {
-                    if let Some(&cur_val) = _cursor.next() {
+                    if let Some(cur_val) = self.take_next(v_opt, _cursor) {
                         Some(cur_val)
                     } else {
                         return mkerr_missing_option_argument(name);
@@ -692,36 +1955,170 @@ impl<'a> Lex<'a> {
                     val
                 }
             }
+            Arg::MaybeNext => {
+                if val.is_none() {
+                    #[cfg(feature = "value_policy")]
+                    let next = if self.value_policy.long_equals_only {
+                        None
+                    } else {
+                        self.take_maybe_next(_cursor)
+                    };
+                    #[cfg(not(feature = "value_policy"))]
+                    let next = self.take_maybe_next(_cursor);
+                    Some(next.unwrap_or(&tail[0..0]))
+                } else {
+                    val
+                }
+            }
         };
+        // the short name with the specifier of long only, like `-c:v`
+        #[cfg(all(feature = "was_long", feature = "stream_spec"))]
+        let was_long = spec.is_none() || v_opt.lon == name;
+        #[cfg(all(feature = "was_long", not(feature = "stream_spec")))]
+        let was_long = true;
         //
         Ok(NameVal {
             opt: v_opt,
             #[cfg(feature = "option_argument")]
             val: val2,
             #[cfg(feature = "was_long")]
-            was_long: true,
+            was_long,
+            #[cfg(feature = "stream_spec")]
+            spec,
+            #[cfg(feature = "expand")]
+            implied: false,
+            #[cfg(feature = "secondary_prefix")]
+            prefix: b'-',
         })
     }
     //
+    fn find_long_name(&'a self, name: &'a str) -> Result<&'a Opt<'a>, OptParseError> {
+        // the empty name matches the rows of the short name only
+        if name.is_empty() {
+            return mkerr_invalid_option(name);
+        }
+        let found = self.opts.binary_search_by_key(&name, |&o| o.lon);
+        match found {
+            Ok(idx) => Ok(&self.opts[idx]),
+            #[cfg(feature = "ignore_case")]
+            _ if self.ignore_case => self.find_ignore_case(name),
+            _ => {
+                #[cfg(feature = "abbreviate")]
+                {
+                    self.find_abbreviate(name)
+                }
+                #[cfg(not(feature = "abbreviate"))]
+                mkerr_invalid_option(name)
+            }
+        }
+    }
+    //
+    // the ASCII case insensitive match, and the abbreviation of it
+    #[cfg(feature = "ignore_case")]
+    fn find_ignore_case(&'a self, name: &'a str) -> Result<&'a Opt<'a>, OptParseError> {
+        let opts = self.opts.iter().filter(|o| !o.lon.is_empty());
+        let exact = dedup_aliases(opts.clone().filter(|o| o.lon.eq_ignore_ascii_case(name)));
+        #[cfg(feature = "abbreviate")]
+        let exact = if exact.is_empty() {
+            dedup_aliases(opts.filter(|o| starts_with_ignore_case(o.lon, name)))
+        } else {
+            exact
+        };
+        match exact.len() {
+            1 => Ok(exact[0]),
+            0 => mkerr_invalid_option(name),
+            _ => mkerr_ambiguous_option(name, &exact),
+        }
+    }
+    //
+    // split `name:spec` into the base name, the option and the specifier,
+    // the base name is not abbreviated.
+    #[cfg(feature = "stream_spec")]
+    fn find_stream_spec(&'a self, name: &'a str) -> Option<(&'a str, &'a Opt<'a>, &'a str)> {
+        if self.stream_specs.is_empty() {
+            return None;
+        }
+        let idx = name.find(':')?;
+        let (base, spec) = (&name[..idx], &name[idx + 1..]);
+        if base.is_empty() || spec.is_empty() {
+            return None;
+        }
+        if self.opts.binary_search_by_key(&name, |&o| o.lon).is_ok() {
+            // the long name has `:` as it is
+            return None;
+        }
+        let opt = match self.opts.binary_search_by_key(&base, |&o| o.lon) {
+            Ok(idx) => &self.opts[idx],
+            Err(_) if self.is_long_only() && base.len() == 1 => {
+                let b_name = base.as_bytes()[0];
+                let idx = self.sho_idx.binary_search_by_key(&b_name, |&o| o.0).ok()?;
+                &self.opts[self.sho_idx[idx].1]
+            }
+            Err(_) => return None,
+        };
+        if self.stream_specs.contains(&opt.num) {
+            Some((base, opt, spec))
+        } else {
+            None
+        }
+    }
+    //
     fn parse_short_name(
         &'a self,
-        _cursor: &mut dyn Iterator<Item = &&'a str>,
+        _cursor: &mut Cursor<'a>,
         tail: &'a str,
         namevals: &mut Vec<NameVal<'a>>,
+        #[cfg(feature = "pass_unknown")] unknown: &mut Vec<Cow<'a, str>>,
     ) -> Result<(), OpErr> {
         #[cfg(not(feature = "single_error"))]
         let mut errs = OpErr::new();
+        #[cfg(all(feature = "single_error", feature = "help_version"))]
+        let mut errs: Option<OpErr> = None;
         let tail_len = tail.len();
         '_ic_iter: for i in 0..tail_len {
             let c_name = &tail[i..=i];
             let b_name = c_name.as_bytes()[0];
+            #[cfg(feature = "long_escape")]
+            if self.long_escape != 0 && b_name == self.long_escape {
+                // the long name after the escape, like `-Wfoo=bar` and `-W foo=bar`
+                let name = if i < tail_len - 1 {
+                    Some(&tail[i + 1..])
+                } else {
+                    _cursor.next().copied()
+                };
+                let res = match name {
+                    Some(name) => self.parse_long_name(_cursor, name),
+                    None => mkerr_missing_option_argument(c_name),
+                };
+                match res {
+                    Ok(nv) => namevals.push(nv),
+                    Err(err) => {
+                        #[cfg(feature = "pass_unknown")]
+                        if self.pass_unknown && err.kind() == OptParseErrorKind::InvalidOption {
+                            unknown.push(Cow::Owned(String::from("--") + name.unwrap_or("")));
+                            break '_ic_iter;
+                        }
+                        self.push_err(
+                            #[cfg(any(not(feature = "single_error"), feature = "help_version"))]
+                            &mut errs,
+                            err,
+                        )?;
+                    }
+                }
+                break '_ic_iter;
+            }
             let v_opt = {
                 let found = self.sho_idx.binary_search_by_key(&b_name, |&o| o.0);
                 match found {
                     Ok(idx) => &self.opts[self.sho_idx[idx].1],
                     _ => {
+                        #[cfg(feature = "pass_unknown")]
+                        if self.pass_unknown {
+                            unknown.push(Cow::Owned(String::from("-") + c_name));
+                            continue '_ic_iter;
+                        }
                         self.push_err(
-                            #[cfg(not(feature = "single_error"))]
+                            #[cfg(any(not(feature = "single_error"), feature = "help_version"))]
                             &mut errs,
                             OptParseError::invalid_option(c_name),
                         )?;
@@ -729,16 +2126,43 @@ impl<'a> Lex<'a> {
                     }
                 }
             };
+            #[cfg(feature = "value_policy")]
+            if self.value_policy.no_cluster && v_opt.has == Arg::No && tail_len > 1 {
+                self.push_err(
+                    #[cfg(any(not(feature = "single_error"), feature = "help_version"))]
+                    &mut errs,
+                    OptParseError::invalid_option(tail),
+                )?;
+                break '_ic_iter;
+            }
             #[cfg(feature = "option_argument")]
             let c_val = if v_opt.has == Arg::No {
                 None
             } else if i < tail_len - 1 {
                 let rest = &tail[i + 1..];
+                #[cfg(feature = "value_policy")]
+                let rest = match self.short_attached_val(c_name, rest) {
+                    Ok(v) => v,
+                    Err(err) => {
+                        self.push_err(
+                            #[cfg(any(not(feature = "single_error"), feature = "help_version"))]
+                            &mut errs,
+                            err,
+                        )?;
+                        break '_ic_iter;
+                    }
+                };
                 namevals.push(NameVal {
                     opt: v_opt,
                     val: Some(rest),
                     #[cfg(feature = "was_long")]
                     was_long: false,
+                    #[cfg(feature = "stream_spec")]
+                    spec: None,
+                    #[cfg(feature = "expand")]
+                    implied: false,
+                    #[cfg(feature = "secondary_prefix")]
+                    prefix: b'-',
                 });
                 break '_ic_iter;
             } else if v_opt.has == Arg::Maybe {
@@ -748,17 +2172,26 @@ impl<'a> Lex<'a> {
                     val: Some(rest),
                     #[cfg(feature = "was_long")]
                     was_long: false,
+                    #[cfg(feature = "stream_spec")]
+                    spec: None,
+                    #[cfg(feature = "expand")]
+                    implied: false,
+                    #[cfg(feature = "secondary_prefix")]
+                    prefix: b'-',
                 });
                 break '_ic_iter;
-            } else if let Some(&cur_val) = _cursor.next() {
+            } else if let Some(cur_val) = self.take_next(v_opt, _cursor) {
                 Some(cur_val)
-            } else {
+            } else if v_opt.has == Arg::Yes {
                 self.push_err(
-                    #[cfg(not(feature = "single_error"))]
+                    #[cfg(any(not(feature = "single_error"), feature = "help_version"))]
                     &mut errs,
                     OptParseError::missing_option_argument(c_name),
                 )?;
                 continue '_ic_iter;
+            } else {
+                // `Arg::MaybeNext` without the next argument
+                Some(&tail[tail_len..tail_len])
             };
             //
             namevals.push(NameVal {
@@ -767,6 +2200,12 @@ impl<'a> Lex<'a> {
                 val: c_val,
                 #[cfg(feature = "was_long")]
                 was_long: false,
+                #[cfg(feature = "stream_spec")]
+                spec: None,
+                #[cfg(feature = "expand")]
+                implied: false,
+                #[cfg(feature = "secondary_prefix")]
+                prefix: b'-',
             });
         }
         //
@@ -776,6 +2215,10 @@ impl<'a> Lex<'a> {
                 return Err(errs);
             }
         }
+        #[cfg(all(feature = "single_error", feature = "help_version"))]
+        if let Some(err) = errs {
+            return Err(err);
+        }
         //
         Ok(())
     }
@@ -783,63 +2226,276 @@ impl<'a> Lex<'a> {
     #[cfg(feature = "long_only")]
     fn parse_long_only(
         &'a self,
-        mut cursor: &mut dyn Iterator<Item = &'a &'a str>,
+        cursor: &mut Cursor<'a>,
         cur: &'a str,
         namevals: &mut Vec<NameVal<'a>>,
+        #[cfg(feature = "pass_unknown")] unknown: &mut Vec<Cow<'a, str>>,
     ) -> Result<(), OpErr> {
-        if cur.len() == 2 {
+        if cur.len() == 2 && self.has_short_name(cur.as_bytes()[1]) {
             //  "-f"
             // short name
-            let e = self.parse_short_name(&mut cursor, &cur[1..], namevals);
-            if let Err(errs) = e {
-                let err = {
-                    #[cfg(not(feature = "single_error"))]
-                    {
-                        &errs.iter().as_slice()[0]
-                    }
-                    #[cfg(feature = "single_error")]
-                    {
-                        &errs
-                    }
-                };
-                if err.kind() == OptParseErrorKind::InvalidOption {
-                    // long name
-                    match self.parse_long_name(&mut cursor, &cur[1..]) {
-                        Ok(nv) => {
-                            namevals.push(nv);
-                        }
-                        Err(err) => {
-                            #[cfg(not(feature = "single_error"))]
-                            {
-                                let mut errs = OpErr::new();
-                                errs.push(err);
-                                return Err(errs);
-                            }
-                            #[cfg(feature = "single_error")]
-                            return Err(err);
-                        }
-                    };
-                } else {
+            return self.parse_short_name(
+                cursor,
+                &cur[1..],
+                namevals,
+                #[cfg(feature = "pass_unknown")]
+                unknown,
+            );
+        }
+        // long name
+        match self.parse_long_name(cursor, &cur[1..]) {
+            Ok(nv) => namevals.push(nv),
+            Err(err) => {
+                #[cfg(feature = "pass_unknown")]
+                if self.pass_unknown && err.kind() == OptParseErrorKind::InvalidOption {
+                    unknown.push(Cow::Borrowed(cur));
+                    return Ok(());
+                }
+                #[cfg(not(feature = "single_error"))]
+                {
+                    let mut errs = OpErr::new();
+                    errs.push(err);
                     return Err(errs);
                 }
+                #[cfg(feature = "single_error")]
+                return Err(err);
+            }
+        };
+        //
+        Ok(())
+    }
+    //
+    // the next argument as the option argument of `opt`
+    #[cfg(feature = "option_argument")]
+    #[inline]
+    fn take_next(&self, opt: &Opt, cursor: &mut Cursor<'a>) -> Option<&'a str> {
+        if opt.has == Arg::MaybeNext {
+            return self.take_maybe_next(cursor);
+        }
+        #[cfg(feature = "strict_value")]
+        if self.is_strict_rejected(opt.num, cursor.as_slice().first()?) {
+            return None;
+        }
+        cursor.next().copied()
+    }
+    //
+    // the option argument attached to the short name, like `value` of `-ovalue`
+    #[cfg(feature = "value_policy")]
+    fn short_attached_val(&self, name: &str, rest: &'a str) -> Result<&'a str, OptParseError> {
+        if self.value_policy.short_strip_equals {
+            if let Some(v) = rest.strip_prefix('=') {
+                return Ok(v);
             }
+        }
+        if self.value_policy.short_separate_only {
+            return Err(OptParseError::unexpected_option_argument(name, rest));
+        }
+        Ok(rest)
+    }
+    //
+    // the option like argument, that is not the option argument of `num`
+    #[cfg(feature = "strict_value")]
+    fn is_strict_rejected(&self, num: OptNum, next: &str) -> bool {
+        let strict = match &self.strict_value {
+            Some(x) if x.nums.is_empty() || x.nums.contains(&num) => x,
+            _ => return false,
+        };
+        if !next.starts_with('-') {
+            false
+        } else if next == "-" {
+            !strict.lone_minus
         } else {
-            match self.parse_long_name(&mut cursor, &cur[1..]) {
-                Ok(nv) => namevals.push(nv),
-                Err(err) => {
-                    #[cfg(not(feature = "single_error"))]
-                    {
-                        let mut errs = OpErr::new();
-                        errs.push(err);
-                        return Err(errs);
+            !(strict.negative_number && is_negative_number(next))
+        }
+    }
+    //
+    // the next argument, that is not an option or a subcommand
+    #[cfg(feature = "option_argument")]
+    fn take_maybe_next(&self, cursor: &mut Cursor<'a>) -> Option<&'a str> {
+        let &next = cursor.as_slice().first()?;
+        if self.is_prefixed(next) && !self.is_negative_number(next) {
+            return None;
+        }
+        #[cfg(feature = "subcommand")]
+        if self.subcmds.contains(&next) {
+            return None;
+        }
+        cursor.next().copied()
+    }
+    //
+    // `-<digits>` of the numeric shorthand option
+    #[cfg(feature = "numeric")]
+    fn parse_numeric_shorthand(&'a self, cur: &'a str) -> Option<NameVal<'a>> {
+        let num = self.numeric_shorthand?;
+        let digits = cur.strip_prefix('-')?;
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let opt = self.opts.iter().find(|o| o.num == num)?;
+        Some(NameVal {
+            opt,
+            val: Some(digits),
+            #[cfg(feature = "was_long")]
+            was_long: false,
+            #[cfg(feature = "stream_spec")]
+            spec: None,
+            #[cfg(feature = "expand")]
+            implied: false,
+            #[cfg(feature = "secondary_prefix")]
+            prefix: b'-',
+        })
+    }
+    //
+    // expand the namevals after `done_len`
+    #[cfg(feature = "expand")]
+    fn expand_namevals(&'a self, namevals: &mut Vec<NameVal<'a>>, done_len: &mut usize) {
+        if self.expands.is_empty() || *done_len == namevals.len() {
+            *done_len = namevals.len();
+            return;
+        }
+        let tail: Vec<NameVal<'a>> = namevals.drain(*done_len..).collect();
+        for nv in tail {
+            let found = self.expands.binary_search_by_key(&nv.opt.num, |e| e.0);
+            #[cfg(feature = "secondary_prefix")]
+            let prefix = nv.prefix;
+            namevals.push(nv);
+            if let Ok(idx) = found {
+                for &(to_num, to_val) in self.expands[idx].1 {
+                    if let Some(opt) = self.opts.iter().find(|o| o.num == to_num) {
+                        namevals.push(NameVal {
+                            opt,
+                            val: to_val,
+                            #[cfg(feature = "was_long")]
+                            was_long: opt.sho == 0u8,
+                            #[cfg(feature = "stream_spec")]
+                            spec: None,
+                            implied: true,
+                            #[cfg(feature = "secondary_prefix")]
+                            prefix,
+                        });
                     }
-                    #[cfg(feature = "single_error")]
-                    return Err(err);
                 }
             }
+        }
+        *done_len = namevals.len();
+    }
+    //
+    #[cfg(feature = "warnings")]
+    fn namevals_warnings(&self, namevals: &[NameVal<'a>], v: &mut Vec<OptParseWarning>) {
+        if self.deprecateds.is_empty() && self.single_values.is_empty() {
+            return;
+        }
+        for (i, nv) in namevals.iter().enumerate() {
+            #[cfg(feature = "expand")]
+            if nv.implied {
+                continue;
+            }
+            let name = nv.opt.lon_or_sho();
+            if !self.deprecateds.is_empty() {
+                // the name as spelled, like `--colour` or `-c`
+                #[cfg(not(feature = "long_only"))]
+                let dash = if nv.was_long { "--" } else { "-" };
+                #[cfg(feature = "long_only")]
+                let dash = "-";
+                let spelled = dash.to_string() + &nv.name();
+                if let Some(&(_, hint)) = self.deprecateds.iter().find(|&&(n, _)| n == spelled) {
+                    v.push(OptParseWarning::deprecated_option(&nv.name(), hint));
+                }
+            }
+            if !self.single_values.contains(&nv.opt.num) {
+                continue;
+            }
+            #[allow(unused_variables)]
+            if let Some(prev) = namevals[..i].iter().rev().find(|x| x.opt.num == nv.opt.num) {
+                #[cfg(feature = "option_argument")]
+                let prev_val = prev.val.unwrap_or("");
+                #[cfg(not(feature = "option_argument"))]
+                let prev_val = "";
+                v.push(OptParseWarning::overridden_option(&name, prev_val));
+            }
+        }
+    }
+    //
+    // remove the occurrences by the policies, and return the errors of the repeated
+    #[cfg(feature = "occurrence")]
+    fn apply_occurrence(
+        &self,
+        namevals: &mut Vec<NameVal<'a>>,
+        #[cfg(feature = "events")] events: &mut Vec<Event<'a>>,
+    ) -> Vec<OptParseError> {
+        let mut errs = Vec::new();
+        if self.occurrences.is_empty() {
+            return errs;
+        }
+        let is_occurrence = |nv: &NameVal, num: OptNum| {
+            #[cfg(feature = "expand")]
+            if nv.implied {
+                return false;
+            }
+            nv.opt.num == num
         };
-        //
-        Ok(())
+        let mut keeps = Vec::with_capacity(namevals.len());
+        for (i, nv) in namevals.iter().enumerate() {
+            let num = nv.opt.num;
+            let policy = self.occurrences.iter().find(|&&(n, _)| n == num);
+            let keep = match policy {
+                _ if !is_occurrence(nv, num) => true,
+                Some(&(_, Occurrence::Last)) => {
+                    !namevals[i + 1..].iter().any(|x| is_occurrence(x, num))
+                }
+                Some(&(_, Occurrence::First)) => {
+                    !namevals[..i].iter().any(|x| is_occurrence(x, num))
+                }
+                Some(&(_, Occurrence::Error)) => {
+                    if namevals[..i].iter().any(|x| is_occurrence(x, num)) {
+                        errs.push(OptParseError::repeated_option(&nv.opt.lon_or_sho()));
+                    }
+                    true
+                }
+                _ => true,
+            };
+            keeps.push(keep);
+        }
+        let mut it = keeps.iter();
+        namevals.retain(|_| *it.next().unwrap_or(&true));
+        // the options of the events are the same as `namevals`
+        #[cfg(feature = "events")]
+        {
+            let mut it = keeps.iter();
+            events.retain(|ev| match ev {
+                Event::NameVal(_) => *it.next().unwrap_or(&true),
+                _ => true,
+            });
+        }
+        errs
+    }
+    //
+    // `tail` is the argument without the prefix, like `verb=1` of `--verb=1`
+    #[cfg(all(feature = "warnings", feature = "abbreviate"))]
+    fn abbreviated_warning(
+        &self,
+        prefix: &str,
+        tail: &str,
+        nv: &NameVal<'a>,
+    ) -> Option<OptParseWarning> {
+        if !self.abbreviation_warning {
+            return None;
+        }
+        let name = tail.split('=').next().unwrap_or(tail);
+        #[cfg(feature = "stream_spec")]
+        let name = name.split(':').next().unwrap_or(name);
+        if name.len() < nv.opt.lon.len() {
+            let hint = format!("use {}{}", prefix, nv.opt.lon);
+            Some(OptParseWarning::abbreviated_option(name, &hint))
+        } else {
+            None
+        }
+    }
+    //
+    #[cfg(feature = "long_only")]
+    #[inline]
+    fn has_short_name(&self, b_name: u8) -> bool {
+        self.sho_idx.binary_search_by_key(&b_name, |&o| o.0).is_ok()
     }
     //
     #[cfg(feature = "subcommand")]
@@ -848,7 +2504,7 @@ impl<'a> Lex<'a> {
         let mut remove_1st = false;
         if !v_free.is_empty() {
             let free_1st = v_free[0];
-            if free_1st != "--" && !free_1st.is_empty() {
+            if free_1st != "--" && free_1st != "-" && !free_1st.is_empty() {
                 #[cfg(feature = "abbreviate")]
                 match self.find_abbreviate_subcmd(free_1st) {
                     Ok(subcmd) => {
@@ -871,6 +2527,52 @@ impl<'a> Lex<'a> {
     }
 }
 
+// the range of `ary` that starts with `name`. `ary` is sorted by `key`,
+// so the exact match, if any, is the first of the range.
+#[cfg(feature = "abbreviate")]
+fn prefix_range<'b, T, F>(ary: &'b [T], name: &str, key: F) -> &'b [T]
+where
+    F: Fn(&T) -> &str,
+{
+    let lo = ary.partition_point(|a| key(a) < name);
+    let len = ary[lo..]
+        .iter()
+        .take_while(|&a| key(a).starts_with(name))
+        .count();
+    &ary[lo..lo + len]
+}
+
+// the rows of the same num are the aliases of an option, the first row is left
+#[cfg(any(feature = "abbreviate", feature = "ignore_case"))]
+fn dedup_aliases<'a, 'b, I>(opts: I) -> Vec<&'b Opt<'a>>
+where
+    I: IntoIterator<Item = &'b Opt<'a>>,
+{
+    let mut v: Vec<&Opt> = Vec::new();
+    for o in opts {
+        if !v.iter().any(|a| a.num == o.num) {
+            v.push(o);
+        }
+    }
+    v
+}
+
+// like `-5`, `-1.5` and `-.5e3`, but not `-inf` and `-nan`
+#[cfg(any(feature = "numeric", feature = "strict_value"))]
+fn is_negative_number(s: &str) -> bool {
+    match s.strip_prefix('-') {
+        Some(t) => {
+            t.starts_with(|c: char| c.is_ascii_digit() || c == '.') && t.parse::<f64>().is_ok()
+        }
+        None => false,
+    }
+}
+
+#[cfg(all(feature = "ignore_case", feature = "abbreviate"))]
+fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+    s.len() >= prefix.len() && s.as_bytes()[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+}
+
 #[inline]
 fn mkerr_invalid_option<T>(name: &str) -> Result<T, OptParseError> {
     Err(OptParseError::invalid_option(name))
@@ -894,7 +2596,7 @@ fn mkerr_invalid_subcommand<T>(name: &str) -> Result<T, OptParseError> {
     Err(OptParseError::invalid_subcommand(name))
 }
 
-#[cfg(feature = "abbreviate")]
+#[cfg(any(feature = "abbreviate", feature = "ignore_case"))]
 fn mkerr_ambiguous_option<'a, T>(
     name: &'a str,
     ambiguous: &[&Opt<'a>],
@@ -907,7 +2609,10 @@ fn mkerr_ambiguous_option<'a, T>(
     Err(OptParseError::ambiguous_option(name, hint.as_str()))
 }
 
-#[cfg(all(feature = "abbreviate", feature = "subcommand"))]
+#[cfg(all(
+    any(feature = "abbreviate", feature = "ignore_case"),
+    feature = "subcommand"
+))]
 fn mkerr_ambiguous_subcommand<'a, T>(
     name: &'a str,
     ambiguous: &[&'a str],
@@ -923,8 +2628,74 @@ fn mkerr_ambiguous_subcommand<'a, T>(
 #[doc(hidden)]
 #[macro_export]
 macro_rules! count {
-    () => (0usize);
-    ( $x:tt $($xs:tt)* ) => (1usize + $crate::count!($($xs)*));
+    (@unit $x:tt) => (());
+    ( $($x:tt)* ) => (<[()]>::len(&[$($crate::count!(@unit $x)),*]));
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! argparse_special {
+    (@help_version help, $num:expr) => {
+        Some($num)
+    };
+    (@help_version version, $num:expr) => {
+        Some($num)
+    };
+    (@help_version $special:ident, $num:expr) => {
+        None
+    };
+    (@occurrence last, $num:expr) => {
+        Some(($num, $crate::Occurrence::Last))
+    };
+    (@occurrence first, $num:expr) => {
+        Some(($num, $crate::Occurrence::First))
+    };
+    (@occurrence once, $num:expr) => {
+        Some(($num, $crate::Occurrence::Error))
+    };
+    (@occurrence accumulate, $num:expr) => {
+        Some(($num, $crate::Occurrence::Accumulate))
+    };
+    (@occurrence $special:ident, $num:expr) => {
+        None
+    };
+    (@help help, $val:expr) => {
+        Some($val)
+    };
+    (@help $special:ident, $val:expr) => {
+        None::<bool>
+    };
+    (@version version, $val:expr) => {
+        Some($val)
+    };
+    (@version $special:ident, $val:expr) => {
+        None::<bool>
+    };
+}
+
+// `HelpVersion::occurrences()` of `argparse!`, that is generated
+// by the features of this crate, not of the caller crate
+#[cfg(any(feature = "occurrence", feature = "dox"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! argparse_occurrences {
+    ( $( ($special:ident, $num:expr) )* ) => {
+        fn occurrences(&self) -> &'static [($crate::OptNum, $crate::Occurrence)] {
+            const SOME_ARY: [Option<($crate::OptNum, $crate::Occurrence)>; $crate::count!($($special)*)] = [
+                $( $crate::argparse_special!(@occurrence $special, $num), )*
+            ];
+            const COUNT: usize = $crate::macro_util::count_some_occurrences(&SOME_ARY);
+            const ARY: [($crate::OptNum, $crate::Occurrence); COUNT] = $crate::macro_util::filter_some_occurrences(&SOME_ARY);
+            &ARY
+        }
+    };
+}
+
+#[cfg(not(any(feature = "occurrence", feature = "dox")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! argparse_occurrences {
+    ( $( $tt:tt )* ) => {};
 }
 
 /// argparse macro
@@ -933,6 +2704,11 @@ macro_rules! count {
 /// struct, and a parser at once. It automatically generates the required sorted tables
 /// and parsing logic.
 ///
+/// The `@special` marks the field: `@help` and `@version` of [`HelpVersion`], or
+/// the occurrence policy of `Occurrence` at the compiling of this crate with
+/// feature = "occurrence", `@last`, `@first`, `@once` (the error) and `@accumulate`
+/// (the `Vec` field).
+///
 /// For large-scale projects with a massive number of options (e.g., hundreds of flags),
 /// it is recommended to continue using [flood-tide-gen](https://crates.io/crates/flood-tide-gen)
 /// to keep your source code clean and maintain the definitions in external files.
@@ -1036,6 +2812,13 @@ macro_rules! argparse {
         /// Short option index array.
         pub const OPT_ARY_SHO_IDX: [(u8, usize); _SHO_COUNT] = $crate::macro_util::gen_sho_idx::<_OPT_COUNT, _SHO_COUNT>(&OPT_ARY);
 
+        const _SPECIAL_COUNT: usize = $crate::count!( $( $( $special )? )* );
+        const _HELP_VERSION_ARY: [Option<$crate::OptNum>; _SPECIAL_COUNT] = [
+            $( $( $crate::argparse_special!(@help_version $special, CmdOP::$field as $crate::OptNum), )? )*
+        ];
+        const _HELP_VERSION_COUNT: usize = $crate::macro_util::count_some_nums(&_HELP_VERSION_ARY);
+        const _HELP_VERSION_NUMS: [$crate::OptNum; _HELP_VERSION_COUNT] = $crate::macro_util::filter_some_nums(&_HELP_VERSION_ARY);
+
         impl $name {
             #[cfg(any(feature = "stop_at_mm", feature = "dox"))]
             pub fn parse(args: &[&str]) -> Result<Self, $crate::OpErr> {
@@ -1075,13 +2858,19 @@ macro_rules! argparse {
 
         impl $crate::HelpVersion for $name {
             fn is_help(&self) -> bool {
-                $( $( if stringify!($special) == "help" { return self.$field; } )? )*
+                $( $( if let Some(b) = $crate::argparse_special!(@help $special, self.$field) { return b; } )? )*
                 false
             }
             fn is_version(&self) -> bool {
-                $( $( if stringify!($special) == "version" { return self.$field; } )? )*
+                $( $( if let Some(b) = $crate::argparse_special!(@version $special, self.$field) { return b; } )? )*
                 false
             }
+            fn help_version_nums(&self) -> &'static [$crate::OptNum] {
+                &_HELP_VERSION_NUMS
+            }
+            $crate::argparse_occurrences!(
+                $( $( ($special, CmdOP::$field as $crate::OptNum) )? )*
+            );
         }
     };
 }
@@ -1,10 +1,15 @@
 //! Utilities for argparse macro.
 
+#[cfg(any(feature = "occurrence", feature = "dox"))]
+use crate::Occurrence;
 use crate::Opt;
+use crate::OptNum;
 use core::cmp::Ordering;
 
 #[cfg(feature = "no_std")]
 use alloc::string::{String, ToString};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
 
 pub const fn str_cmp(a: &str, b: &str) -> Ordering {
     let (a, b) = (a.as_bytes(), b.as_bytes());
@@ -123,6 +128,81 @@ pub const fn gen_sho_idx<const N: usize, const M: usize>(opts: &[Opt; N]) -> [(u
     res
 }
 
+pub const fn count_some_nums<const N: usize>(ary: &[Option<OptNum>; N]) -> usize {
+    let mut i = 0;
+    let mut count = 0;
+    while i < N {
+        if ary[i].is_some() {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+pub const fn filter_some_nums<const N: usize, const M: usize>(
+    ary: &[Option<OptNum>; N],
+) -> [OptNum; M] {
+    let mut res = [0; M];
+    let mut i = 0;
+    let mut count = 0;
+    while i < N {
+        if let Some(num) = ary[i] {
+            res[count] = num;
+            count += 1;
+        }
+        i += 1;
+    }
+    res
+}
+
+// the nums of the occurrence policies, for `count_some_nums()` and `filter_some_nums()`
+#[cfg(any(feature = "occurrence", feature = "dox"))]
+const fn occurrence_nums<const N: usize>(
+    ary: &[Option<(OptNum, Occurrence)>; N],
+) -> [Option<OptNum>; N] {
+    let mut res = [None; N];
+    let mut i = 0;
+    while i < N {
+        if let Some((num, _)) = ary[i] {
+            res[i] = Some(num);
+        }
+        i += 1;
+    }
+    res
+}
+
+#[cfg(any(feature = "occurrence", feature = "dox"))]
+pub const fn count_some_occurrences<const N: usize>(
+    ary: &[Option<(OptNum, Occurrence)>; N],
+) -> usize {
+    count_some_nums(&occurrence_nums(ary))
+}
+
+#[cfg(any(feature = "occurrence", feature = "dox"))]
+pub const fn filter_some_occurrences<const N: usize, const M: usize>(
+    ary: &[Option<(OptNum, Occurrence)>; N],
+) -> [(OptNum, Occurrence); M] {
+    let nums: [OptNum; M] = filter_some_nums(&occurrence_nums(ary));
+    let mut res = [(0, Occurrence::Accumulate); M];
+    let mut j = 0;
+    while j < M {
+        res[j].0 = nums[j];
+        let mut i = 0;
+        while i < N {
+            if let Some((num, policy)) = ary[i] {
+                if num == nums[j] {
+                    res[j].1 = policy;
+                    break;
+                }
+            }
+            i += 1;
+        }
+        j += 1;
+    }
+    res
+}
+
 /// Trait for setting field from option value.
 pub trait ArgparseSet {
     fn argparse_set(&mut self, val: Option<&str>, name: &str) -> Result<(), crate::OptParseError>;
@@ -155,6 +235,26 @@ impl ArgparseSet for Option<String> {
     }
 }
 
+#[cfg(not(feature = "no_std"))]
+impl ArgparseSet for std::path::PathBuf {
+    fn argparse_set(&mut self, val: Option<&str>, _name: &str) -> Result<(), crate::OptParseError> {
+        if let Some(s) = val {
+            *self = std::path::PathBuf::from(s);
+        }
+        Ok(())
+    }
+}
+
+/// the accumulated values, like `@accumulate` of `argparse!`
+impl<T: ArgparseSet + Default> ArgparseSet for Vec<T> {
+    fn argparse_set(&mut self, val: Option<&str>, name: &str) -> Result<(), crate::OptParseError> {
+        let mut v = T::default();
+        v.argparse_set(val, name)?;
+        self.push(v);
+        Ok(())
+    }
+}
+
 macro_rules! impl_argparse_set_parse {
     ($($t:ty),*) => {
         $(
@@ -39,8 +39,11 @@ mod basic {
             let len = 8;
             #[cfg(feature = "option_argument")]
             let len = len + 16;
-            #[cfg(feature = "was_long")]
+            // the bools of was_long and implied, and the u8 of prefix
+            #[cfg(any(feature = "was_long", feature = "expand", feature = "secondary_prefix"))]
             let len = len + 8;
+            #[cfg(feature = "stream_spec")]
+            let len = len + 16;
             assert_eq!(std::mem::size_of::<NameVal>(), len);
         }
         #[cfg(target_pointer_width = "32")]
@@ -48,8 +51,11 @@ mod basic {
             let len = 4;
             #[cfg(feature = "option_argument")]
             let len = len + 8;
-            #[cfg(feature = "was_long")]
+            // the bools of was_long and implied, and the u8 of prefix
+            #[cfg(any(feature = "was_long", feature = "expand", feature = "secondary_prefix"))]
             let len = len + 4;
+            #[cfg(feature = "stream_spec")]
+            let len = len + 8;
             assert_eq!(std::mem::size_of::<NameVal>(), len);
         }
         //
@@ -60,6 +66,14 @@ mod basic {
             let len = len + 8;
             #[cfg(feature = "subcommand")]
             let len = len + 16;
+            #[cfg(feature = "pass_unknown")]
+            let len = len + 24;
+            #[cfg(feature = "events")]
+            let len = len + 24;
+            #[cfg(feature = "warnings")]
+            let len = len + 24;
+            #[cfg(feature = "passthrough")]
+            let len = len + 16;
             assert_eq!(std::mem::size_of::<Tokens>(), len);
         }
         #[cfg(target_pointer_width = "32")]
@@ -69,6 +83,14 @@ mod basic {
             let len = len + 4;
             #[cfg(feature = "subcommand")]
             let len = len + 8;
+            #[cfg(feature = "pass_unknown")]
+            let len = len + 12;
+            #[cfg(feature = "events")]
+            let len = len + 12;
+            #[cfg(feature = "warnings")]
+            let len = len + 12;
+            #[cfg(feature = "passthrough")]
+            let len = len + 8;
             assert_eq!(std::mem::size_of::<Tokens>(), len);
         }
     }
@@ -0,0 +1,131 @@
+mod check_validate {
+    use flood_tide::check;
+    use flood_tide::check::Diagnostic;
+    use flood_tide::check::Severity;
+    #[cfg(feature = "option_argument")]
+    use flood_tide::Arg;
+    use flood_tide::Opt;
+    //
+    #[test]
+    fn validate_ok() {
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "",     has: Arg::No,  num: 1, },
+            Opt { sho: b'b', lon: "barn", has: Arg::No,  num: 2, },
+            Opt { sho: 0u8,  lon: "eat",  has: Arg::Yes, num: 3, },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "",     num: 1, },
+            Opt { sho: b'b', lon: "barn", num: 2, },
+            Opt { sho: 0u8,  lon: "eat",  num: 3, },
+        ];
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'b',1)];
+        //
+        assert!(check::validate(&opt_ary, &opt_ary_sho_idx).is_empty());
+    }
+    #[test]
+    fn validate_opt_ary() {
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: 0u8, lon: "barn",  has: Arg::No,  num: 1, },
+            Opt { sho: 0u8, lon: "barn",  has: Arg::No,  num: 2, },
+            Opt { sho: 0u8, lon: "-dash", has: Arg::No,  num: 3, },
+            Opt { sho: 0u8, lon: "eat=",  has: Arg::Yes, num: 1, },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: 0u8, lon: "barn",  num: 1, },
+            Opt { sho: 0u8, lon: "barn",  num: 2, },
+            Opt { sho: 0u8, lon: "-dash", num: 3, },
+            Opt { sho: 0u8, lon: "eat=",  num: 1, },
+        ];
+        //
+        let thing = check::validate(&opt_ary, &[]);
+        assert_eq!(
+            thing,
+            vec![
+                Diagnostic::DuplicateLong(1),
+                Diagnostic::UnsortedOpt(2),
+                Diagnostic::LongStartsWithDash(2),
+                Diagnostic::DuplicateNum(3),
+                Diagnostic::LongContainsEq(3),
+            ]
+        );
+        let thing: Vec<Severity> = thing.iter().map(|diag| diag.severity()).collect();
+        assert_eq!(
+            thing,
+            vec![
+                Severity::Error,
+                Severity::Error,
+                Severity::Warning,
+                Severity::Warning,
+                Severity::Error,
+            ]
+        );
+    }
+    #[test]
+    fn validate_sho_idx_ary() {
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "",     has: Arg::No, num: 1, },
+            Opt { sho: b'b', lon: "barn", has: Arg::No, num: 2, },
+            Opt { sho: b'c', lon: "cat",  has: Arg::No, num: 3, },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "",     num: 1, },
+            Opt { sho: b'b', lon: "barn", num: 2, },
+            Opt { sho: b'c', lon: "cat",  num: 3, },
+        ];
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'b',1),(b'a',0),(b'a',0),(b'c',1),(b'd',3)];
+        //
+        let thing = check::validate(&opt_ary, &opt_ary_sho_idx);
+        assert_eq!(
+            thing,
+            vec![
+                Diagnostic::UnsortedShoIdx(1),
+                Diagnostic::DuplicateShort(2),
+                Diagnostic::WrongShoIdx(3),
+                Diagnostic::DanglingShoIdx(4),
+            ]
+        );
+    }
+    #[test]
+    fn diagnostic_display() {
+        assert_eq!(
+            format!("{}", Diagnostic::UnsortedOpt(3)),
+            "opt_ary[3]: not sorted by long name"
+        );
+        assert_eq!(
+            format!("{}", Diagnostic::WrongShoIdx(1)),
+            "sho_idx_ary[1]: index points at wrong row"
+        );
+    }
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "invalid opt tables: opt_ary[1]: not sorted by long name")]
+    fn lex_create_with_invalid_tables() {
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: 0u8, lon: "eat",  has: Arg::No, num: 1, },
+            Opt { sho: 0u8, lon: "barn", has: Arg::No, num: 2, },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: 0u8, lon: "eat",  num: 1, },
+            Opt { sho: 0u8, lon: "barn", num: 2, },
+        ];
+        let _lex = flood_tide::Lex::create_with(&opt_ary, &[]);
+    }
+}
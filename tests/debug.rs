@@ -45,6 +45,12 @@ mod fmt_debug {
             val: Some("value"),
             #[cfg(feature = "was_long")]
             was_long: true,
+            #[cfg(feature = "stream_spec")]
+            spec: None,
+            #[cfg(feature = "expand")]
+            implied: false,
+            #[cfg(feature = "secondary_prefix")]
+            prefix: b'-',
         };
         //
         let thing = format!("{nv:?}");
@@ -56,6 +62,12 @@ mod fmt_debug {
         let expect = expect + ", val: Some(\"value\")";
         #[cfg(feature = "was_long")]
         let expect = expect + ", was_long: true";
+        #[cfg(feature = "stream_spec")]
+        let expect = expect + ", spec: None";
+        #[cfg(feature = "expand")]
+        let expect = expect + ", implied: false";
+        #[cfg(feature = "secondary_prefix")]
+        let expect = expect + ", prefix: 45";
         let expect = expect + " }";
         assert_eq!(thing, expect);
     }
@@ -74,6 +86,12 @@ mod fmt_debug {
             val: Some("value"),
             #[cfg(feature = "was_long")]
             was_long: false,
+            #[cfg(feature = "stream_spec")]
+            spec: None,
+            #[cfg(feature = "expand")]
+            implied: false,
+            #[cfg(feature = "secondary_prefix")]
+            prefix: b'-',
         };
         //
         let thing = nv.name();
@@ -109,6 +127,12 @@ mod fmt_debug {
                     val: Some("value1"),
                     #[cfg(feature = "was_long")]
                     was_long: true,
+                    #[cfg(feature = "stream_spec")]
+                    spec: None,
+                    #[cfg(feature = "expand")]
+                    implied: false,
+                    #[cfg(feature = "secondary_prefix")]
+                    prefix: b'-',
                 },
                 NameVal {
                     opt: &opt2,
@@ -116,6 +140,12 @@ mod fmt_debug {
                     val: Some("value2"),
                     #[cfg(feature = "was_long")]
                     was_long: true,
+                    #[cfg(feature = "stream_spec")]
+                    spec: None,
+                    #[cfg(feature = "expand")]
+                    implied: false,
+                    #[cfg(feature = "secondary_prefix")]
+                    prefix: b'-',
                 },
                 NameVal {
                     opt: &opt3,
@@ -123,13 +153,27 @@ mod fmt_debug {
                     val: None,
                     #[cfg(feature = "was_long")]
                     was_long: false,
+                    #[cfg(feature = "stream_spec")]
+                    spec: None,
+                    #[cfg(feature = "expand")]
+                    implied: false,
+                    #[cfg(feature = "secondary_prefix")]
+                    prefix: b'-',
                 },
             ],
             free: vec!["free1", "free2", "free3"],
+            #[cfg(feature = "passthrough")]
+            mm_index: Some(0),
             #[cfg(feature = "subcommand")]
             subcmd: Some("command"),
             #[cfg(feature = "stop_at_mm")]
             double_m: true,
+            #[cfg(feature = "pass_unknown")]
+            unknown: vec!["--unknown".into()],
+            #[cfg(feature = "events")]
+            events: vec![],
+            #[cfg(feature = "warnings")]
+            warnings: vec![],
         };
         //
         let thing = format!("{tks:?}");
@@ -175,9 +219,31 @@ mod fmt_debug {
                 " NameVal { opt: Opt { sho: 99, lon: \"name3\", num: 3 } }",
             );
         let expect = expect + "],";
+        // the last field of each NameVal
+        #[cfg(feature = "stream_spec")]
+        let expect = expect
+            .replace(" }, NameVal", ", spec: None }, NameVal")
+            .replace(" }],", ", spec: None }],");
+        #[cfg(feature = "expand")]
+        let expect = expect
+            .replace(" }, NameVal", ", implied: false }, NameVal")
+            .replace(" }],", ", implied: false }],");
+        #[cfg(feature = "secondary_prefix")]
+        let expect = expect
+            .replace(" }, NameVal", ", prefix: 45 }, NameVal")
+            .replace(" }],", ", prefix: 45 }],");
         #[cfg(feature = "stop_at_mm")]
         let expect = expect + " double_m: true,";
-        let expect = expect + subcmd + " free: [\"free1\", \"free2\", \"free3\"] }";
+        let expect = expect + subcmd + " free: [\"free1\", \"free2\", \"free3\"]";
+        #[cfg(feature = "passthrough")]
+        let expect = expect + ", mm_index: Some(0)";
+        #[cfg(feature = "pass_unknown")]
+        let expect = expect + ", unknown: [\"--unknown\"]";
+        #[cfg(feature = "events")]
+        let expect = expect + ", events: []";
+        #[cfg(feature = "warnings")]
+        let expect = expect + ", warnings: []";
+        let expect = expect + " }";
         assert_eq!(thing, expect);
     }
     #[test]
@@ -249,7 +315,42 @@ mod fmt_debug {
             " Opt { sho: 115, lon: \"section\", num: 2 }]",
         );
         let sho_idx_s = ", sho_idx: [(105, 0), (110, 1), (115, 2)]";
-        let expect = "Lex { ".to_string() + opts_s + sho_idx_s + subcmd + " }";
+        let expect = "Lex { ".to_string() + opts_s + sho_idx_s + subcmd;
+        #[cfg(all(feature = "subcommand", feature = "abbreviate"))]
+        let expect = expect + ", subcmds_sorted: true";
+        #[cfg(feature = "stream_spec")]
+        let expect = expect + ", stream_specs: []";
+        #[cfg(feature = "expand")]
+        let expect = expect + ", expands: []";
+        #[cfg(feature = "warnings")]
+        let expect = expect + ", deprecateds: []";
+        #[cfg(feature = "secondary_prefix")]
+        let expect = expect + ", sec_prefix: 0";
+        #[cfg(feature = "numeric")]
+        let expect = expect + ", negative_number: false, numeric_shorthand: None";
+        #[cfg(feature = "strict_value")]
+        let expect = expect + ", strict_value: None";
+        #[cfg(feature = "value_policy")]
+        let expect = expect
+            + concat!(
+                ", value_policy: ValuePolicy { long_equals_only: false,",
+                " short_separate_only: false, no_cluster: false, short_strip_equals: false }"
+            );
+        #[cfg(feature = "long_escape")]
+        let expect = expect + ", long_escape: 0";
+        #[cfg(feature = "help_version")]
+        let expect = expect + ", help_version: []";
+        #[cfg(feature = "warnings")]
+        let expect = expect + ", single_values: []";
+        #[cfg(all(feature = "warnings", feature = "abbreviate"))]
+        let expect = expect + ", abbreviation_warning: false";
+        #[cfg(feature = "occurrence")]
+        let expect = expect + ", occurrences: []";
+        #[cfg(feature = "pass_unknown")]
+        let expect = expect + ", pass_unknown: false";
+        #[cfg(feature = "ignore_case")]
+        let expect = expect + ", ignore_case: false";
+        let expect = expect + " }";
         assert_eq!(thing, expect);
     }
     #[cfg(feature = "long_only")]
@@ -321,7 +422,42 @@ mod fmt_debug {
             " Opt { sho: 115, lon: \"section\", num: 2 }]",
         );
         let sho_idx_s = ", sho_idx: [(105, 0), (110, 1), (115, 2)]";
-        let expect = "Lex { ".to_string() + opts_s + sho_idx_s + subcmd + " }";
+        let expect = "Lex { ".to_string() + opts_s + sho_idx_s + subcmd;
+        #[cfg(all(feature = "subcommand", feature = "abbreviate"))]
+        let expect = expect + ", subcmds_sorted: true";
+        #[cfg(feature = "stream_spec")]
+        let expect = expect + ", stream_specs: []";
+        #[cfg(feature = "expand")]
+        let expect = expect + ", expands: []";
+        #[cfg(feature = "warnings")]
+        let expect = expect + ", deprecateds: []";
+        #[cfg(feature = "secondary_prefix")]
+        let expect = expect + ", sec_prefix: 0";
+        #[cfg(feature = "numeric")]
+        let expect = expect + ", negative_number: false, numeric_shorthand: None";
+        #[cfg(feature = "strict_value")]
+        let expect = expect + ", strict_value: None";
+        #[cfg(feature = "value_policy")]
+        let expect = expect
+            + concat!(
+                ", value_policy: ValuePolicy { long_equals_only: false,",
+                " short_separate_only: false, no_cluster: false, short_strip_equals: false }"
+            );
+        #[cfg(feature = "long_escape")]
+        let expect = expect + ", long_escape: 0";
+        #[cfg(feature = "help_version")]
+        let expect = expect + ", help_version: []";
+        #[cfg(feature = "warnings")]
+        let expect = expect + ", single_values: []";
+        #[cfg(all(feature = "warnings", feature = "abbreviate"))]
+        let expect = expect + ", abbreviation_warning: false";
+        #[cfg(feature = "occurrence")]
+        let expect = expect + ", occurrences: []";
+        #[cfg(feature = "pass_unknown")]
+        let expect = expect + ", pass_unknown: false";
+        #[cfg(feature = "ignore_case")]
+        let expect = expect + ", ignore_case: false";
+        let expect = expect + " }";
         assert_eq!(thing, expect);
     }
     #[cfg(feature = "subcommand")]
@@ -385,7 +521,42 @@ mod fmt_debug {
             " Opt { sho: 115, lon: \"section\", num: 2 }]",
         );
         let sho_idx_s = ", sho_idx: [(105, 0), (110, 1), (115, 2)]";
-        let expect = "Lex { ".to_string() + opts_s + sho_idx_s + subcmd + " }";
+        let expect = "Lex { ".to_string() + opts_s + sho_idx_s + subcmd;
+        #[cfg(all(feature = "subcommand", feature = "abbreviate"))]
+        let expect = expect + ", subcmds_sorted: true";
+        #[cfg(feature = "stream_spec")]
+        let expect = expect + ", stream_specs: []";
+        #[cfg(feature = "expand")]
+        let expect = expect + ", expands: []";
+        #[cfg(feature = "warnings")]
+        let expect = expect + ", deprecateds: []";
+        #[cfg(feature = "secondary_prefix")]
+        let expect = expect + ", sec_prefix: 0";
+        #[cfg(feature = "numeric")]
+        let expect = expect + ", negative_number: false, numeric_shorthand: None";
+        #[cfg(feature = "strict_value")]
+        let expect = expect + ", strict_value: None";
+        #[cfg(feature = "value_policy")]
+        let expect = expect
+            + concat!(
+                ", value_policy: ValuePolicy { long_equals_only: false,",
+                " short_separate_only: false, no_cluster: false, short_strip_equals: false }"
+            );
+        #[cfg(feature = "long_escape")]
+        let expect = expect + ", long_escape: 0";
+        #[cfg(feature = "help_version")]
+        let expect = expect + ", help_version: []";
+        #[cfg(feature = "warnings")]
+        let expect = expect + ", single_values: []";
+        #[cfg(all(feature = "warnings", feature = "abbreviate"))]
+        let expect = expect + ", abbreviation_warning: false";
+        #[cfg(feature = "occurrence")]
+        let expect = expect + ", occurrences: []";
+        #[cfg(feature = "pass_unknown")]
+        let expect = expect + ", pass_unknown: false";
+        #[cfg(feature = "ignore_case")]
+        let expect = expect + ", ignore_case: false";
+        let expect = expect + " }";
         assert_eq!(thing, expect);
     }
 }
@@ -0,0 +1,263 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "events")]
+#[cfg(feature = "option_argument")]
+#[cfg(not(feature = "long_only"))]
+mod test_events {
+    use flood_tide::check;
+    use flood_tide::Arg;
+    use flood_tide::Event;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+
+    fn to_strings(events: &[Event]) -> Vec<String> {
+        events
+            .iter()
+            .map(|ev| match ev {
+                Event::NameVal(nv) => match nv.val {
+                    Some(v) => format!("{}={}", nv.opt.lon_or_sho(), v),
+                    None => nv.opt.lon_or_sho(),
+                },
+                Event::Free(s) => format!("free:{}", s),
+                #[cfg(feature = "stop_at_mm")]
+                Event::DoubleM => "--".to_string(),
+                #[cfg(feature = "subcommand")]
+                Event::SubCmd(s) => format!("subcmd:{}", s),
+                #[cfg(feature = "pass_unknown")]
+                Event::Unknown(s) => format!("unknown:{}", s),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tokens_events() {
+        #[rustfmt::skip]
+        let args = vec![
+            "-ab", "in1", "--barn", "--eat", "val", "out1",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            A = 1,
+            Barn = 2,
+            Eat = 3,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'a',  lon: "",     has: Arg::No,  num: CmdOP::A.to(), },
+            Opt { sho: b'b',  lon: "barn", has: Arg::No,  num: CmdOP::Barn.to(), },
+            Opt { sho: b'\0', lon: "eat",  has: Arg::Yes, num: CmdOP::Eat.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'b',1)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        #[cfg(not(feature = "stop_at_free"))]
+        assert_eq!(
+            to_strings(&tokens.events),
+            vec!["a", "barn", "free:in1", "barn", "eat=val", "free:out1"]
+        );
+        #[cfg(feature = "stop_at_free")]
+        assert_eq!(
+            to_strings(&tokens.events),
+            vec![
+                "a",
+                "barn",
+                "free:in1",
+                "free:--barn",
+                "free:--eat",
+                "free:val",
+                "free:out1"
+            ]
+        );
+    }
+
+    #[cfg(feature = "stop_at_mm")]
+    #[test]
+    fn tokens_events_double_m() {
+        #[rustfmt::skip]
+        let args = vec![
+            "-a", "--", "-b", "free1",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            A = 1,
+            Barn = 2,
+            Eat = 3,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'a',  lon: "",     has: Arg::No,  num: CmdOP::A.to(), },
+            Opt { sho: b'b',  lon: "barn", has: Arg::No,  num: CmdOP::Barn.to(), },
+            Opt { sho: b'\0', lon: "eat",  has: Arg::Yes, num: CmdOP::Eat.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'b',1)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        assert!(tokens.double_m);
+        assert_eq!(tokens.free, vec!["-b", "free1"]);
+        assert_eq!(
+            to_strings(&tokens.events),
+            vec!["a", "--", "free:-b", "free:free1"]
+        );
+    }
+
+    #[cfg(feature = "subcommand")]
+    #[test]
+    fn tokens_events_subcommand() {
+        #[rustfmt::skip]
+        let args = vec![
+            "-a", "--eat=val", "cmd", "-b",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            A = 1,
+            Barn = 2,
+            Eat = 3,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'a',  lon: "",     has: Arg::No,  num: CmdOP::A.to(), },
+            Opt { sho: b'b',  lon: "barn", has: Arg::No,  num: CmdOP::Barn.to(), },
+            Opt { sho: b'\0', lon: "eat",  has: Arg::Yes, num: CmdOP::Eat.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'b',1)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).subcmd(&["cmd"])
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        assert_eq_tokens_subcmd!(tokens, "cmd");
+        assert_eq!(tokens.free, vec!["-b"]);
+        assert_eq!(
+            to_strings(&tokens.events),
+            vec!["a", "eat=val", "subcmd:cmd", "free:-b"]
+        );
+    }
+
+    #[cfg(feature = "pass_unknown")]
+    #[test]
+    fn tokens_events_unknown() {
+        #[rustfmt::skip]
+        let args = vec![
+            "-a", "--foo=bar", "-bx", "free1",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            A = 1,
+            Barn = 2,
+            Eat = 3,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'a',  lon: "",     has: Arg::No,  num: CmdOP::A.to(), },
+            Opt { sho: b'b',  lon: "barn", has: Arg::No,  num: CmdOP::Barn.to(), },
+            Opt { sho: b'\0', lon: "eat",  has: Arg::Yes, num: CmdOP::Eat.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'b',1)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).pass_unknown()
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        assert_eq!(
+            to_strings(&tokens.events),
+            vec!["a", "unknown:--foo=bar", "barn", "unknown:-x", "free:free1"]
+        );
+    }
+}
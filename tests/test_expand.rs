@@ -0,0 +1,287 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "expand")]
+mod test_expand {
+    use flood_tide::check;
+    use flood_tide::Arg;
+    use flood_tide::Expand;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_expand() {
+        #[rustfmt::skip]
+        let args = vec![
+            "-va", "--format", "csv", "--long", "free1",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            All = 1,
+            Format,
+            Long,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "all",     has: Arg::No,  num: CmdOP::All.to(), },
+            Opt { sho: b'f', lon: "format",  has: Arg::Yes, num: CmdOP::Format.to(), },
+            Opt { sho: b'l', lon: "long",    has: Arg::No,  num: CmdOP::Long.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'f',1),(b'l',2),(b'v',3)];
+
+        #[rustfmt::skip]
+        let expand_ary: [Expand; 2] = [
+            (CmdOP::All.to(),  &[(CmdOP::Verbose.to(), None), (CmdOP::Format.to(), Some("json"))]),
+            (CmdOP::Long.to(), &[(CmdOP::Format.to(), Some("long"))]),
+        ];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).expand(&expand_ary)
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        assert_eq!(tokens.namevals.len(), 7);
+        assert_eq_tokens_namevals!(tokens, 0, b'v', "verbose", None, CmdOP::Verbose);
+        assert_eq_tokens_namevals!(tokens, 1, b'a', "all", None, CmdOP::All);
+        assert_eq_tokens_namevals!(tokens, 2, b'v', "verbose", None, CmdOP::Verbose);
+        assert_eq_tokens_namevals!(tokens, 3, b'f', "format", Some("json"), CmdOP::Format);
+        assert_eq_tokens_namevals!(tokens, 4, b'f', "format", Some("csv"), CmdOP::Format);
+        assert_eq_tokens_namevals!(tokens, 5, b'l', "long", None, CmdOP::Long);
+        assert_eq_tokens_namevals!(tokens, 6, b'f', "format", Some("long"), CmdOP::Format);
+        let thing: Vec<bool> = tokens.namevals.iter().map(|nv| nv.implied).collect();
+        assert_eq!(thing, vec![false, false, true, true, false, false, true]);
+        assert_eq!(tokens.free, vec!["free1"]);
+    }
+
+    #[cfg(feature = "long_only")]
+    #[test]
+    fn tokens_expand_long_only() {
+        #[rustfmt::skip]
+        let args = vec![
+            "-a", "-format", "csv", "-long",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            All = 1,
+            Format,
+            Long,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "all",     has: Arg::No,  num: CmdOP::All.to(), },
+            Opt { sho: b'f', lon: "format",  has: Arg::Yes, num: CmdOP::Format.to(), },
+            Opt { sho: b'l', lon: "long",    has: Arg::No,  num: CmdOP::Long.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'f',1),(b'l',2),(b'v',3)];
+
+        #[rustfmt::skip]
+        let expand_ary: [Expand; 2] = [
+            (CmdOP::All.to(),  &[(CmdOP::Verbose.to(), None), (CmdOP::Format.to(), Some("json"))]),
+            (CmdOP::Long.to(), &[(CmdOP::Format.to(), Some("long"))]),
+        ];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).expand(&expand_ary)
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        let thing: Vec<(OptNum, Option<&str>, bool)> = tokens
+            .namevals
+            .iter()
+            .map(|nv| (nv.opt.num, nv.val, nv.implied))
+            .collect();
+        assert_eq!(
+            thing,
+            vec![
+                (CmdOP::All.to(), None, false),
+                (CmdOP::Verbose.to(), None, true),
+                (CmdOP::Format.to(), Some("json"), true),
+                (CmdOP::Format.to(), Some("csv"), false),
+                (CmdOP::Long.to(), None, false),
+                (CmdOP::Format.to(), Some("long"), true),
+            ]
+        );
+    }
+
+    #[cfg(feature = "events")]
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_expand_events() {
+        use flood_tide::Event;
+        let args = vec!["-v", "-l", "free1"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            All = 1,
+            Format,
+            Long,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "all",     has: Arg::No,  num: CmdOP::All.to(), },
+            Opt { sho: b'f', lon: "format",  has: Arg::Yes, num: CmdOP::Format.to(), },
+            Opt { sho: b'l', lon: "long",    has: Arg::No,  num: CmdOP::Long.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'f',1),(b'l',2),(b'v',3)];
+
+        #[rustfmt::skip]
+        let expand_ary: [Expand; 2] = [
+            (CmdOP::All.to(),  &[(CmdOP::Verbose.to(), None), (CmdOP::Format.to(), Some("json"))]),
+            (CmdOP::Long.to(), &[(CmdOP::Format.to(), Some("long"))]),
+        ];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).expand(&expand_ary)
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        let thing: Vec<String> = tokens
+            .events
+            .iter()
+            .map(|ev| match ev {
+                Event::NameVal(nv) => nv.opt.lon.to_string(),
+                Event::Free(s) => s.to_string(),
+                #[allow(unreachable_patterns)]
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(thing, vec!["verbose", "long", "format", "free1"]);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "invalid expand table: not sorted")]
+    fn lex_expand_not_sorted() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            All = 1,
+            Format,
+            Long,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "all",     has: Arg::No,  num: CmdOP::All.to(), },
+            Opt { sho: b'f', lon: "format",  has: Arg::Yes, num: CmdOP::Format.to(), },
+            Opt { sho: b'l', lon: "long",    has: Arg::No,  num: CmdOP::Long.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'f',1),(b'l',2),(b'v',3)];
+        #[rustfmt::skip]
+        let expand_ary: [Expand; 2] = [
+            (CmdOP::Long.to(), &[(CmdOP::Format.to(), Some("long"))]),
+            (CmdOP::All.to(),  &[(CmdOP::Verbose.to(), None)]),
+        ];
+        let _lex = Lex::create_with(&opt_ary, &opt_ary_sho_idx).expand(&expand_ary);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "invalid expand table: 1: unknown num: 9")]
+    fn lex_expand_unknown_num() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            All = 1,
+            Format,
+            Long,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "all",     has: Arg::No,  num: CmdOP::All.to(), },
+            Opt { sho: b'f', lon: "format",  has: Arg::Yes, num: CmdOP::Format.to(), },
+            Opt { sho: b'l', lon: "long",    has: Arg::No,  num: CmdOP::Long.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'f',1),(b'l',2),(b'v',3)];
+        #[rustfmt::skip]
+        let expand_ary: [Expand; 1] = [
+            (CmdOP::All.to(), &[(9, None)]),
+        ];
+        let _lex = Lex::create_with(&opt_ary, &opt_ary_sho_idx).expand(&expand_ary);
+    }
+}
@@ -0,0 +1,208 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "groups")]
+#[cfg(feature = "option_argument")]
+mod test_groups {
+    use flood_tide::check;
+    use flood_tide::Anchor;
+    use flood_tide::Arg;
+    use flood_tide::Group;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+
+    // ex) `-y -s 10 (-i in1)`
+    fn to_string(group: &Group) -> String {
+        let mut v: Vec<String> = group
+            .namevals
+            .iter()
+            .map(|nv| match nv.val {
+                Some(val) => format!("-{} {}", nv.opt.sho as char, val),
+                None => format!("-{}", nv.opt.sho as char),
+            })
+            .collect();
+        match &group.anchor {
+            Some(Anchor::NameVal(nv)) => {
+                v.push(format!("(-{} {})", nv.opt.sho as char, nv.val.unwrap()))
+            }
+            Some(Anchor::Free(s)) => v.push(format!("({})", s)),
+            None => {}
+        }
+        v.join(" ")
+    }
+
+    #[cfg(not(feature = "stop_at_free"))]
+    #[test]
+    fn tokens_groups() {
+        #[rustfmt::skip]
+        let args = vec![
+            "-y", "-s", "10", "-i", "in1", "-i", "in2",
+            "-c", "copy", "out1", "-c", "h264", "-s", "5", "out2", "-y",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Codec = 1,
+            Input,
+            Seek,
+            Yes,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'c', lon: "codec", has: Arg::Yes, num: CmdOP::Codec.to(), },
+            Opt { sho: b'i', lon: "input", has: Arg::Yes, num: CmdOP::Input.to(), },
+            Opt { sho: b's', lon: "seek",  has: Arg::Yes, num: CmdOP::Seek.to(), },
+            Opt { sho: b'y', lon: "yes",   has: Arg::No,  num: CmdOP::Yes.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'c',0),(b'i',1),(b's',2),(b'y',3)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        let groups = tokens.groups(&[CmdOP::Input.to()]);
+        let thing: Vec<String> = groups.iter().map(to_string).collect();
+        assert_eq!(
+            thing,
+            vec![
+                "-y -s 10 (-i in1)",
+                "(-i in2)",
+                "-c copy (out1)",
+                "-c h264 -s 5 (out2)",
+                "-y",
+            ]
+        );
+        assert!(groups[4].anchor.is_none());
+    }
+
+    #[cfg(feature = "stop_at_mm")]
+    #[test]
+    fn tokens_groups_double_m() {
+        #[rustfmt::skip]
+        let args = vec![
+            "-i", "in1", "-c", "copy", "--", "-out1",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Codec = 1,
+            Input,
+            Seek,
+            Yes,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'c', lon: "codec", has: Arg::Yes, num: CmdOP::Codec.to(), },
+            Opt { sho: b'i', lon: "input", has: Arg::Yes, num: CmdOP::Input.to(), },
+            Opt { sho: b's', lon: "seek",  has: Arg::Yes, num: CmdOP::Seek.to(), },
+            Opt { sho: b'y', lon: "yes",   has: Arg::No,  num: CmdOP::Yes.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'c',0),(b'i',1),(b's',2),(b'y',3)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        let groups = tokens.groups(&[CmdOP::Input.to()]);
+        let thing: Vec<String> = groups.iter().map(to_string).collect();
+        assert_eq!(thing, vec!["(-i in1)", "-c copy (-out1)"]);
+    }
+
+    #[test]
+    fn tokens_groups_no_anchor() {
+        let args = vec!["-y", "-c", "copy"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Codec = 1,
+            Input,
+            Seek,
+            Yes,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'c', lon: "codec", has: Arg::Yes, num: CmdOP::Codec.to(), },
+            Opt { sho: b'i', lon: "input", has: Arg::Yes, num: CmdOP::Input.to(), },
+            Opt { sho: b's', lon: "seek",  has: Arg::Yes, num: CmdOP::Seek.to(), },
+            Opt { sho: b'y', lon: "yes",   has: Arg::No,  num: CmdOP::Yes.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'c',0),(b'i',1),(b's',2),(b'y',3)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        let groups = tokens.groups(&[CmdOP::Input.to()]);
+        let thing: Vec<String> = groups.iter().map(to_string).collect();
+        assert_eq!(thing, vec!["-y -c copy"]);
+        //
+        let groups = tokens.groups(&[]);
+        assert_eq!(groups.len(), 1);
+        assert!(groups[0].anchor.is_none());
+    }
+}
@@ -0,0 +1,284 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "help_version")]
+#[cfg(not(feature = "long_only"))]
+mod test_help_version {
+    use flood_tide::check;
+    #[cfg(feature = "option_argument")]
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+
+    #[test]
+    fn tokens_help_version_wins() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Help = 1,
+            Output,
+            Verbose,
+            Version,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'h', lon: "help",    has: Arg::No,  num: CmdOP::Help.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", has: Arg::No,  num: CmdOP::Version.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'h', lon: "help",    num: CmdOP::Help.to(), },
+            Opt { sho: b'o', lon: "output",  num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", num: CmdOP::Version.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'V',3),(b'h',0),(b'o',1),(b'v',2)];
+
+        let help_version_nums = [CmdOP::Help.to(), CmdOP::Version.to()];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).help_version(&help_version_nums)
+        };
+        for args in [
+            vec!["--bogus", "--help"],
+            vec!["-x", "-V"],
+            vec!["-xh"],
+            vec!["--help", "--verbose=1"],
+        ] {
+            let tokens = match lex.tokens_from(&args) {
+                Ok(t) => t,
+                Err(e) => {
+                    assert_eq!(format!("{}", e), "", "{:?}", args);
+                    unreachable!();
+                }
+            };
+            assert!(tokens
+                .namevals
+                .iter()
+                .any(|nv| help_version_nums.contains(&nv.opt.num)));
+        }
+    }
+
+    #[test]
+    fn tokens_help_version_not_given() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Help = 1,
+            Output,
+            Verbose,
+            Version,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'h', lon: "help",    has: Arg::No,  num: CmdOP::Help.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", has: Arg::No,  num: CmdOP::Version.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'h', lon: "help",    num: CmdOP::Help.to(), },
+            Opt { sho: b'o', lon: "output",  num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", num: CmdOP::Version.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'V',3),(b'h',0),(b'o',1),(b'v',2)];
+
+        let help_version_nums = [CmdOP::Help.to(), CmdOP::Version.to()];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).help_version(&help_version_nums)
+        };
+        let args = vec!["--bogus", "--verbose"];
+        assert!(lex.tokens_from(&args).is_err());
+        //
+        // without the setup
+        let lex = Lex::create_with(&opt_ary, &opt_ary_sho_idx);
+        let args = vec!["--bogus", "--help"];
+        assert!(lex.tokens_from(&args).is_err());
+    }
+
+    #[cfg(feature = "option_argument")]
+    #[test]
+    fn tokens_help_version_as_argument() {
+        // `--help` is the option argument of `--output`
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Help = 1,
+            Output,
+            Verbose,
+            Version,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'h', lon: "help",    has: Arg::No,  num: CmdOP::Help.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", has: Arg::No,  num: CmdOP::Version.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'h', lon: "help",    num: CmdOP::Help.to(), },
+            Opt { sho: b'o', lon: "output",  num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", num: CmdOP::Version.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'V',3),(b'h',0),(b'o',1),(b'v',2)];
+
+        let help_version_nums = [CmdOP::Help.to(), CmdOP::Version.to()];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).help_version(&help_version_nums)
+        };
+        let args = vec!["--output", "--help", "--bogus"];
+        assert!(lex.tokens_from(&args).is_err());
+        #[cfg(feature = "pass_unknown")]
+        {
+            let lex = lex.pass_unknown();
+            assert_eq!(lex.tokens_from(&args).unwrap().namevals.len(), 1);
+        }
+    }
+
+    #[cfg(feature = "subcommand")]
+    #[test]
+    fn tokens_help_version_subcmd() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Help = 1,
+            Output,
+            Verbose,
+            Version,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'h', lon: "help",    has: Arg::No,  num: CmdOP::Help.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", has: Arg::No,  num: CmdOP::Version.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'h', lon: "help",    num: CmdOP::Help.to(), },
+            Opt { sho: b'o', lon: "output",  num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", num: CmdOP::Version.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'V',3),(b'h',0),(b'o',1),(b'v',2)];
+
+        let help_version_nums = [CmdOP::Help.to(), CmdOP::Version.to()];
+        let subcmd_ary = ["add", "list"];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+                .help_version(&help_version_nums)
+                .subcmd(&subcmd_ary)
+        };
+        let args = vec!["--help", "bogus"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert!(tokens.subcmd.is_none());
+        assert_eq!(tokens.free, vec!["bogus"]);
+        //
+        let args = vec!["--help", "list"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq_tokens_subcmd!(tokens, "list");
+        //
+        let args = vec!["--verbose", "bogus"];
+        assert!(lex.tokens_from(&args).is_err());
+    }
+
+    #[cfg(feature = "stop_at_mm")]
+    mod argparse {
+        use flood_tide::{argparse, Arg, HelpVersion};
+
+        argparse! {
+            pub struct MyConf {
+                (help,    bool, b'h', "help",    Arg::No,  "display help", @help),
+                (version, bool, b'V', "version", Arg::No,  "display version", @version),
+                (count,   u32,  b'c', "count",   Arg::Yes, "count value"),
+            }
+        }
+
+        #[test]
+        fn parse_help_version_wins() {
+            let conf = MyConf::parse(&["--bogus", "--help"]).unwrap();
+            assert!(conf.is_help());
+            let conf = MyConf::parse(&["-x", "-V"]).unwrap();
+            assert!(conf.is_version());
+            #[cfg(feature = "option_argument")]
+            {
+                let conf = MyConf::parse(&["--count=abc", "--help"]).unwrap();
+                assert!(conf.is_help());
+                assert!(MyConf::parse(&["--count=abc"]).is_err());
+            }
+            assert!(MyConf::parse(&["--bogus"]).is_err());
+        }
+    }
+}
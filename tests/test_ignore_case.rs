@@ -0,0 +1,500 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "ignore_case")]
+mod test_ignore_case {
+    use flood_tide::check;
+    #[cfg(feature = "option_argument")]
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+
+    fn long(name: &str) -> String {
+        #[cfg(not(feature = "long_only"))]
+        let r = format!("--{}", name);
+        #[cfg(feature = "long_only")]
+        let r = format!("-{}", name);
+        r
+    }
+
+    #[test]
+    fn tokens_ignore_case_long() {
+        let args: Vec<String> = ["VERBOSE", "Version", "COLOR", "foo", "Foo"]
+            .iter()
+            .map(|s| long(s))
+            .collect();
+        let args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Colors,
+            FooUpper,
+            FooLower,
+            Verbose,
+            Version,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "Foo",     has: Arg::No, num: CmdOP::FooUpper.to(), },
+            Opt { sho: 0u8,  lon: "color",   has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colors",  has: Arg::No, num: CmdOP::Colors.to(), },
+            Opt { sho: 0u8,  lon: "foo",     has: Arg::No, num: CmdOP::FooLower.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No, num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", has: Arg::No, num: CmdOP::Version.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "Foo",     num: CmdOP::FooUpper.to(), },
+            Opt { sho: 0u8,  lon: "color",   num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colors",  num: CmdOP::Colors.to(), },
+            Opt { sho: 0u8,  lon: "foo",     num: CmdOP::FooLower.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", num: CmdOP::Version.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'V',5),(b'v',4)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).ignore_case()
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+        let thing: Vec<OptNum> = tokens.namevals.iter().map(|nv| nv.opt.num).collect();
+        assert_eq!(
+            thing,
+            vec![
+                CmdOP::Verbose.to(),
+                CmdOP::Version.to(),
+                CmdOP::Color.to(),
+                CmdOP::FooLower.to(),
+                CmdOP::FooUpper.to(),
+            ]
+        );
+        let thing: Vec<&str> = tokens.namevals.iter().map(|nv| nv.opt.lon).collect();
+        assert_eq!(thing, vec!["verbose", "version", "color", "foo", "Foo"]);
+    }
+
+    #[test]
+    fn tokens_ignore_case_not_setup() {
+        let arg = long("VERBOSE");
+        let args = vec![arg.as_str()];
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Colors,
+            FooUpper,
+            FooLower,
+            Verbose,
+            Version,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "Foo",     has: Arg::No, num: CmdOP::FooUpper.to(), },
+            Opt { sho: 0u8,  lon: "color",   has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colors",  has: Arg::No, num: CmdOP::Colors.to(), },
+            Opt { sho: 0u8,  lon: "foo",     has: Arg::No, num: CmdOP::FooLower.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No, num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", has: Arg::No, num: CmdOP::Version.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "Foo",     num: CmdOP::FooUpper.to(), },
+            Opt { sho: 0u8,  lon: "color",   num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colors",  num: CmdOP::Colors.to(), },
+            Opt { sho: 0u8,  lon: "foo",     num: CmdOP::FooLower.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", num: CmdOP::Version.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'V',5),(b'v',4)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        match lex.tokens_from(&args) {
+            Ok(_) => unreachable!(),
+            Err(e) => assert_eq!(format!("{}", e), "Invalid option: VERBOSE"),
+        }
+        let arg = long("verbose");
+        let args = vec![arg.as_str()];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals[0].opt.num, CmdOP::Verbose.to());
+    }
+
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_ignore_case_short() {
+        let args = vec!["-v", "-V"];
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Colors,
+            FooUpper,
+            FooLower,
+            Verbose,
+            Version,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "Foo",     has: Arg::No, num: CmdOP::FooUpper.to(), },
+            Opt { sho: 0u8,  lon: "color",   has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colors",  has: Arg::No, num: CmdOP::Colors.to(), },
+            Opt { sho: 0u8,  lon: "foo",     has: Arg::No, num: CmdOP::FooLower.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No, num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", has: Arg::No, num: CmdOP::Version.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "Foo",     num: CmdOP::FooUpper.to(), },
+            Opt { sho: 0u8,  lon: "color",   num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colors",  num: CmdOP::Colors.to(), },
+            Opt { sho: 0u8,  lon: "foo",     num: CmdOP::FooLower.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", num: CmdOP::Version.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'V',5),(b'v',4)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).ignore_case()
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        let thing: Vec<OptNum> = tokens.namevals.iter().map(|nv| nv.opt.num).collect();
+        assert_eq!(thing, vec![CmdOP::Verbose.to(), CmdOP::Version.to()]);
+    }
+
+    #[test]
+    fn tokens_ignore_case_ambiguous() {
+        let arg = long("FOO");
+        let args = vec![arg.as_str()];
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Colors,
+            FooUpper,
+            FooLower,
+            Verbose,
+            Version,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "Foo",     has: Arg::No, num: CmdOP::FooUpper.to(), },
+            Opt { sho: 0u8,  lon: "color",   has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colors",  has: Arg::No, num: CmdOP::Colors.to(), },
+            Opt { sho: 0u8,  lon: "foo",     has: Arg::No, num: CmdOP::FooLower.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No, num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", has: Arg::No, num: CmdOP::Version.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "Foo",     num: CmdOP::FooUpper.to(), },
+            Opt { sho: 0u8,  lon: "color",   num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colors",  num: CmdOP::Colors.to(), },
+            Opt { sho: 0u8,  lon: "foo",     num: CmdOP::FooLower.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", num: CmdOP::Version.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'V',5),(b'v',4)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).ignore_case()
+        };
+        match lex.tokens_from(&args) {
+            Ok(_) => unreachable!(),
+            Err(e) => assert_eq!(
+                format!("{}", e),
+                "Ambiguous option: FOO: possibilities: '--Foo' '--foo'"
+            ),
+        }
+    }
+
+    #[cfg(feature = "abbreviate")]
+    #[test]
+    fn tokens_ignore_case_abbreviate() {
+        let args: Vec<String> = ["VERB", "vERSI", "COLOR", "Colors"]
+            .iter()
+            .map(|s| long(s))
+            .collect();
+        let args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Colors,
+            FooUpper,
+            FooLower,
+            Verbose,
+            Version,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "Foo",     has: Arg::No, num: CmdOP::FooUpper.to(), },
+            Opt { sho: 0u8,  lon: "color",   has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colors",  has: Arg::No, num: CmdOP::Colors.to(), },
+            Opt { sho: 0u8,  lon: "foo",     has: Arg::No, num: CmdOP::FooLower.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No, num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", has: Arg::No, num: CmdOP::Version.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "Foo",     num: CmdOP::FooUpper.to(), },
+            Opt { sho: 0u8,  lon: "color",   num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colors",  num: CmdOP::Colors.to(), },
+            Opt { sho: 0u8,  lon: "foo",     num: CmdOP::FooLower.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", num: CmdOP::Version.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'V',5),(b'v',4)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).ignore_case()
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        let thing: Vec<OptNum> = tokens.namevals.iter().map(|nv| nv.opt.num).collect();
+        assert_eq!(
+            thing,
+            vec![
+                CmdOP::Verbose.to(),
+                CmdOP::Version.to(),
+                CmdOP::Color.to(),
+                CmdOP::Colors.to(),
+            ]
+        );
+        //
+        let arg = long("VER");
+        let args = vec![arg.as_str()];
+        match lex.tokens_from(&args) {
+            Ok(_) => unreachable!(),
+            Err(e) => assert_eq!(
+                format!("{}", e),
+                "Ambiguous option: VER: possibilities: '--verbose' '--version'"
+            ),
+        }
+    }
+
+    #[cfg(not(feature = "abbreviate"))]
+    #[test]
+    fn tokens_ignore_case_invalid() {
+        let arg = long("VERB");
+        let args = vec![arg.as_str()];
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Colors,
+            FooUpper,
+            FooLower,
+            Verbose,
+            Version,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "Foo",     has: Arg::No, num: CmdOP::FooUpper.to(), },
+            Opt { sho: 0u8,  lon: "color",   has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colors",  has: Arg::No, num: CmdOP::Colors.to(), },
+            Opt { sho: 0u8,  lon: "foo",     has: Arg::No, num: CmdOP::FooLower.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No, num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", has: Arg::No, num: CmdOP::Version.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "Foo",     num: CmdOP::FooUpper.to(), },
+            Opt { sho: 0u8,  lon: "color",   num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colors",  num: CmdOP::Colors.to(), },
+            Opt { sho: 0u8,  lon: "foo",     num: CmdOP::FooLower.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", num: CmdOP::Version.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'V',5),(b'v',4)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).ignore_case()
+        };
+        match lex.tokens_from(&args) {
+            Ok(_) => unreachable!(),
+            Err(e) => assert_eq!(format!("{}", e), "Invalid option: VERB"),
+        }
+    }
+
+    #[cfg(feature = "subcommand")]
+    #[test]
+    fn tokens_ignore_case_subcmd() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Colors,
+            FooUpper,
+            FooLower,
+            Verbose,
+            Version,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "Foo",     has: Arg::No, num: CmdOP::FooUpper.to(), },
+            Opt { sho: 0u8,  lon: "color",   has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colors",  has: Arg::No, num: CmdOP::Colors.to(), },
+            Opt { sho: 0u8,  lon: "foo",     has: Arg::No, num: CmdOP::FooLower.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No, num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", has: Arg::No, num: CmdOP::Version.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "Foo",     num: CmdOP::FooUpper.to(), },
+            Opt { sho: 0u8,  lon: "color",   num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colors",  num: CmdOP::Colors.to(), },
+            Opt { sho: 0u8,  lon: "foo",     num: CmdOP::FooLower.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+            Opt { sho: b'V', lon: "version", num: CmdOP::Version.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'V',5),(b'v',4)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+                .ignore_case()
+                .subcmd(&["Apply", "add", "remove"])
+        };
+        let args = vec!["ADD"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq_tokens_subcmd!(tokens, "add");
+        let args = vec!["apply"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq_tokens_subcmd!(tokens, "Apply");
+        //
+        #[cfg(feature = "abbreviate")]
+        {
+            let args = vec!["REM"];
+            let tokens = lex.tokens_from(&args).unwrap();
+            assert_eq_tokens_subcmd!(tokens, "remove");
+            let args = vec!["A"];
+            match lex.tokens_from(&args) {
+                Ok(_) => unreachable!(),
+                Err(e) => assert_eq!(
+                    format!("{}", e),
+                    "Ambiguous subcommand: A: possibilities: 'Apply' 'add'"
+                ),
+            }
+        }
+        #[cfg(not(feature = "abbreviate"))]
+        {
+            let args = vec!["REM"];
+            match lex.tokens_from(&args) {
+                Ok(_) => unreachable!(),
+                Err(e) => assert_eq!(format!("{}", e), "Invalid subcommand: REM"),
+            }
+        }
+    }
+}
@@ -0,0 +1,273 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "long_escape")]
+#[cfg(not(feature = "long_only"))]
+mod test_long_escape {
+    use flood_tide::check;
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+
+    fn err_string(lex: &Lex, args: &[&str]) -> String {
+        match lex.tokens_from(args) {
+            Ok(t) => format!("{:?}", t),
+            Err(e) => format!("{}", e),
+        }
+    }
+
+    #[test]
+    fn tokens_long_escape() {
+        #[rustfmt::skip]
+        let args = vec![
+            "-W", "verbose", "-Woutput=o1", "-W", "output=o2", "-W", "output", "o3",
+            "-vWcolor", "-W", "color=always",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Output,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "color",   has: Arg::Maybe, num: CmdOP::Color.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes,   num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,    num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',1),(b'v',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).long_escape(b'W')
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+        let thing: Vec<(OptNum, Option<&str>)> = tokens
+            .namevals
+            .iter()
+            .map(|nv| (nv.opt.num, nv.val))
+            .collect();
+        assert_eq!(
+            thing,
+            vec![
+                (CmdOP::Verbose.to(), None),
+                (CmdOP::Output.to(), Some("o1")),
+                (CmdOP::Output.to(), Some("o2")),
+                (CmdOP::Output.to(), Some("o3")),
+                (CmdOP::Verbose.to(), None),
+                (CmdOP::Color.to(), Some("")),
+                (CmdOP::Color.to(), Some("always")),
+            ]
+        );
+        assert!(tokens.free.is_empty());
+    }
+
+    #[cfg(feature = "abbreviate")]
+    #[test]
+    fn tokens_long_escape_abbreviate() {
+        let args = vec!["-W", "verb", "-Wout=o1"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Output,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "color",   has: Arg::Maybe, num: CmdOP::Color.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes,   num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,    num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',1),(b'v',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).long_escape(b'W')
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        let thing: Vec<(OptNum, Option<&str>)> = tokens
+            .namevals
+            .iter()
+            .map(|nv| (nv.opt.num, nv.val))
+            .collect();
+        assert_eq!(
+            thing,
+            vec![
+                (CmdOP::Verbose.to(), None),
+                (CmdOP::Output.to(), Some("o1"))
+            ]
+        );
+    }
+
+    #[test]
+    fn tokens_long_escape_error() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Output,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "color",   has: Arg::Maybe, num: CmdOP::Color.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes,   num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,    num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',1),(b'v',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).long_escape(b'W')
+        };
+        let args = vec!["-W"];
+        assert_eq!(err_string(&lex, &args), "Missing option argument: W");
+        let args = vec!["-Wverbose=1"];
+        assert_eq!(
+            err_string(&lex, &args),
+            "Unexpected option argument: verbose: 1"
+        );
+        //
+        // without the long escape
+        {
+            let lex = Lex::create_with(&opt_ary, &opt_ary_sho_idx);
+            let args = vec!["-W", "verbose"];
+            assert_eq!(err_string(&lex, &args), "Invalid option: W");
+        }
+    }
+
+    #[test]
+    fn tokens_long_escape_invalid() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Output,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "color",   has: Arg::Maybe, num: CmdOP::Color.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes,   num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,    num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',1),(b'v',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).long_escape(b'W')
+        };
+        let args = vec!["-W", "bogus"];
+        assert_eq!(err_string(&lex, &args), "Invalid option: bogus");
+    }
+
+    #[cfg(feature = "pass_unknown")]
+    #[test]
+    fn tokens_long_escape_unknown() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Output,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "color",   has: Arg::Maybe, num: CmdOP::Color.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes,   num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,    num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',1),(b'v',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+                .long_escape(b'W')
+                .pass_unknown()
+        };
+        let args = vec!["-Wbogus", "-W", "bogus=1", "-v"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals.len(), 1);
+        assert_eq!(tokens.unknown, vec!["--bogus", "--bogus=1"]);
+        //
+        #[cfg(feature = "secondary_prefix")]
+        {
+            let lex = lex.secondary_prefix(b'+');
+            let args = vec!["+Wbogus", "+q"];
+            let tokens = lex.tokens_from(&args).unwrap();
+            assert_eq!(tokens.unknown, vec!["++bogus", "+q"]);
+        }
+    }
+}
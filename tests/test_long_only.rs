@@ -256,4 +256,71 @@ mod test_long_only {
 
         assert!(tokens.double_m);
     }
+
+    #[cfg(feature = "long_only")]
+    #[test]
+    fn tokens_long_only_stdin() {
+        #[rustfmt::skip]
+        let args = vec![
+            "-a", "-", "-b",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            A = 1,
+            B = 2,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'\0', lon: "a", has: Arg::No, num: CmdOP::A.to(), },
+            Opt { sho: b'\0', lon: "b", has: Arg::No, num: CmdOP::B.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'\0', lon: "a", num: CmdOP::A.to(), },
+            Opt { sho: b'\0', lon: "b", num: CmdOP::B.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        assert_eq_tokens_namevals!(tokens, 0, 0u8, "a", None, CmdOP::A);
+        assert_eq_tokens_free!(tokens, 0, "-");
+        #[cfg(feature = "stop_at_free")]
+        {
+            assert_eq!(tokens.namevals.len(), 1);
+            assert_eq_tokens_free!(tokens, 1, "-b");
+        }
+        #[cfg(not(feature = "stop_at_free"))]
+        {
+            assert_eq_tokens_namevals!(tokens, 1, 0u8, "b", None, CmdOP::B);
+            assert_eq!(tokens.free.len(), 1);
+        }
+    }
 }
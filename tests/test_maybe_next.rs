@@ -0,0 +1,232 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "option_argument")]
+mod test_maybe_next {
+    use flood_tide::check;
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+
+    fn long(name: &str) -> String {
+        #[cfg(not(feature = "long_only"))]
+        let r = format!("--{}", name);
+        #[cfg(feature = "long_only")]
+        let r = format!("-{}", name);
+        r
+    }
+
+    fn nums_vals<'a>(tokens: &flood_tide::Tokens<'a>) -> Vec<(OptNum, Option<&'a str>)> {
+        tokens
+            .namevals
+            .iter()
+            .map(|nv| (nv.opt.num, nv.val))
+            .collect()
+    }
+
+    #[test]
+    fn tokens_maybe_next_long() {
+        let color = long("color");
+        let quiet = long("quiet");
+        let color_eq = long("color=never");
+        let args = vec![
+            color.as_str(),
+            "always",
+            color.as_str(),
+            quiet.as_str(),
+            color_eq.as_str(),
+            color.as_str(),
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Inplace,
+            Quiet,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "color",    has: Arg::MaybeNext, num: CmdOP::Color.to(), },
+            Opt { sho: b'i', lon: "in-place", has: Arg::MaybeNext, num: CmdOP::Inplace.to(), },
+            Opt { sho: b'q', lon: "quiet",    has: Arg::No,        num: CmdOP::Quiet.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'i',1),(b'q',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+        assert_eq!(
+            nums_vals(&tokens),
+            vec![
+                (CmdOP::Color.to(), Some("always")),
+                (CmdOP::Color.to(), Some("")),
+                (CmdOP::Quiet.to(), None),
+                (CmdOP::Color.to(), Some("never")),
+                (CmdOP::Color.to(), Some("")),
+            ]
+        );
+        assert!(tokens.free.is_empty());
+    }
+
+    #[cfg(feature = "stop_at_mm")]
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_maybe_next_short() {
+        let args = vec!["-i", ".bak", "-qi", "-i.orig", "-i", "-", "-i", "--", "f1"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Inplace,
+            Quiet,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "color",    has: Arg::MaybeNext, num: CmdOP::Color.to(), },
+            Opt { sho: b'i', lon: "in-place", has: Arg::MaybeNext, num: CmdOP::Inplace.to(), },
+            Opt { sho: b'q', lon: "quiet",    has: Arg::No,        num: CmdOP::Quiet.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'i',1),(b'q',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(
+            nums_vals(&tokens),
+            vec![
+                (CmdOP::Inplace.to(), Some(".bak")),
+                (CmdOP::Quiet.to(), None),
+                (CmdOP::Inplace.to(), Some("")),
+                (CmdOP::Inplace.to(), Some(".orig")),
+                (CmdOP::Inplace.to(), Some("-")),
+                (CmdOP::Inplace.to(), Some("")),
+            ]
+        );
+        assert_eq!(tokens.free, vec!["f1"]);
+    }
+
+    #[cfg(feature = "subcommand")]
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_maybe_next_subcmd() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Inplace,
+            Quiet,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "color",    has: Arg::MaybeNext, num: CmdOP::Color.to(), },
+            Opt { sho: b'i', lon: "in-place", has: Arg::MaybeNext, num: CmdOP::Inplace.to(), },
+            Opt { sho: b'q', lon: "quiet",    has: Arg::No,        num: CmdOP::Quiet.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'i',1),(b'q',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).subcmd(&["apply", "list"])
+        };
+        let args = vec!["-i", "list", "-q"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(nums_vals(&tokens), vec![(CmdOP::Inplace.to(), Some(""))]);
+        assert_eq_tokens_subcmd!(tokens, "list");
+        //
+        let args = vec!["-i", "bak", "list"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(nums_vals(&tokens), vec![(CmdOP::Inplace.to(), Some("bak"))]);
+        assert_eq_tokens_subcmd!(tokens, "list");
+    }
+
+    #[cfg(feature = "numeric")]
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_maybe_next_negative_number() {
+        let args = vec!["-i", "-5"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Inplace,
+            Quiet,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: 0u8,  lon: "color",    has: Arg::MaybeNext, num: CmdOP::Color.to(), },
+            Opt { sho: b'i', lon: "in-place", has: Arg::MaybeNext, num: CmdOP::Inplace.to(), },
+            Opt { sho: b'q', lon: "quiet",    has: Arg::No,        num: CmdOP::Quiet.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'i',1),(b'q',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).negative_number()
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(nums_vals(&tokens), vec![(CmdOP::Inplace.to(), Some("-5"))]);
+    }
+}
@@ -0,0 +1,253 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "numeric")]
+#[cfg(not(feature = "long_only"))]
+mod test_numeric {
+    use flood_tide::check;
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+
+    #[cfg(not(feature = "stop_at_free"))]
+    #[test]
+    fn tokens_numeric_negative_number() {
+        let args = vec!["--offset=-3", "-1.5", "-q", "-5", "-.5e3"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Lines = 1,
+            Offset,
+            Quiet,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'n', lon: "lines",  has: Arg::Yes, num: CmdOP::Lines.to(), },
+            Opt { sho: 0u8,  lon: "offset", has: Arg::Yes, num: CmdOP::Offset.to(), },
+            Opt { sho: b'q', lon: "quiet",  has: Arg::No,  num: CmdOP::Quiet.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'n',0),(b'q',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).negative_number()
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+        assert_eq!(tokens.namevals.len(), 2);
+        assert_eq!(tokens.namevals[0].opt.num, CmdOP::Offset.to());
+        assert_eq!(tokens.namevals[0].val, Some("-3"));
+        assert_eq!(tokens.namevals[1].opt.num, CmdOP::Quiet.to());
+        assert_eq!(tokens.free, vec!["-1.5", "-5", "-.5e3"]);
+    }
+
+    #[cfg(feature = "stop_at_free")]
+    #[test]
+    fn tokens_numeric_negative_number_stop_at_free() {
+        let args = vec!["-q", "-5", "-q"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Lines = 1,
+            Offset,
+            Quiet,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'n', lon: "lines",  has: Arg::Yes, num: CmdOP::Lines.to(), },
+            Opt { sho: 0u8,  lon: "offset", has: Arg::Yes, num: CmdOP::Offset.to(), },
+            Opt { sho: b'q', lon: "quiet",  has: Arg::No,  num: CmdOP::Quiet.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'n',0),(b'q',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).negative_number()
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals.len(), 1);
+        assert_eq!(tokens.free, vec!["-5", "-q"]);
+    }
+
+    #[test]
+    fn tokens_numeric_not_negative_number() {
+        // the options, the invalid options or the unknown options
+        let is_not_free = |lex: &Lex, arg| match lex.tokens_from(&[arg]) {
+            Ok(t) => t.free.is_empty(),
+            Err(_) => true,
+        };
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Lines = 1,
+            Offset,
+            Quiet,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'n', lon: "lines",  has: Arg::Yes, num: CmdOP::Lines.to(), },
+            Opt { sho: 0u8,  lon: "offset", has: Arg::Yes, num: CmdOP::Offset.to(), },
+            Opt { sho: b'q', lon: "quiet",  has: Arg::No,  num: CmdOP::Quiet.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'n',0),(b'q',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        assert!(is_not_free(&lex, "-5"));
+        //
+        let lex = lex.negative_number();
+        for arg in ["-inf", "-nan", "-1e", "-5x"] {
+            assert!(is_not_free(&lex, arg), "{}", arg);
+        }
+    }
+
+    #[test]
+    fn tokens_numeric_shorthand() {
+        let args = vec!["-20", "-q", "-n", "5"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Lines = 1,
+            Offset,
+            Quiet,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'n', lon: "lines",  has: Arg::Yes, num: CmdOP::Lines.to(), },
+            Opt { sho: 0u8,  lon: "offset", has: Arg::Yes, num: CmdOP::Offset.to(), },
+            Opt { sho: b'q', lon: "quiet",  has: Arg::No,  num: CmdOP::Quiet.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'n',0),(b'q',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).numeric_shorthand(CmdOP::Lines.to())
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        let thing: Vec<(OptNum, Option<&str>)> = tokens
+            .namevals
+            .iter()
+            .map(|nv| (nv.opt.num, nv.val))
+            .collect();
+        assert_eq!(
+            thing,
+            vec![
+                (CmdOP::Lines.to(), Some("20")),
+                (CmdOP::Quiet.to(), None),
+                (CmdOP::Lines.to(), Some("5")),
+            ]
+        );
+        assert!(tokens.free.is_empty());
+        //
+        // the shorthand is only digits
+        let args = vec!["-1.5"];
+        if let Ok(t) = lex.tokens_from(&args) {
+            assert!(t.namevals.is_empty());
+        }
+    }
+
+    #[cfg(not(feature = "stop_at_free"))]
+    #[test]
+    fn tokens_numeric_shorthand_and_negative_number() {
+        let args = vec!["-20", "-1.5"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Lines = 1,
+            Offset,
+            Quiet,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'n', lon: "lines",  has: Arg::Yes, num: CmdOP::Lines.to(), },
+            Opt { sho: 0u8,  lon: "offset", has: Arg::Yes, num: CmdOP::Offset.to(), },
+            Opt { sho: b'q', lon: "quiet",  has: Arg::No,  num: CmdOP::Quiet.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'n',0),(b'q',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+                .numeric_shorthand(CmdOP::Lines.to())
+                .negative_number()
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals.len(), 1);
+        assert_eq!(tokens.namevals[0].val, Some("20"));
+        assert_eq!(tokens.free, vec!["-1.5"]);
+    }
+}
@@ -0,0 +1,262 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "occurrence")]
+#[cfg(feature = "option_argument")]
+#[cfg(not(feature = "long_only"))]
+mod test_occurrence {
+    use flood_tide::check;
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Occurrence;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+    use flood_tide::OptParseErrorKind;
+
+    fn nums_vals<'a>(tokens: &flood_tide::Tokens<'a>) -> Vec<(OptNum, Option<&'a str>)> {
+        tokens
+            .namevals
+            .iter()
+            .map(|nv| (nv.opt.num, nv.val))
+            .collect()
+    }
+
+    #[test]
+    fn tokens_occurrence() {
+        #[rustfmt::skip]
+        let args = vec![
+            "-o", "o1", "-i", "i1", "-D", "a=1", "--output=o2", "-v",
+            "--input=i2", "-Db=2", "-o", "o3",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Define = 1,
+            Input,
+            Output,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'D', lon: "define",  has: Arg::Yes, num: CmdOP::Define.to(), },
+            Opt { sho: b'i', lon: "input",   has: Arg::Yes, num: CmdOP::Input.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'D',0),(b'i',1),(b'o',2),(b'v',3)];
+
+        #[rustfmt::skip]
+        let occurrence_ary = [
+            (CmdOP::Define.to(),  Occurrence::Accumulate),
+            (CmdOP::Input.to(),   Occurrence::First),
+            (CmdOP::Output.to(),  Occurrence::Last),
+            (CmdOP::Verbose.to(), Occurrence::Error),
+        ];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).occurrence(&occurrence_ary)
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+        assert_eq!(
+            nums_vals(&tokens),
+            vec![
+                (CmdOP::Input.to(), Some("i1")),
+                (CmdOP::Define.to(), Some("a=1")),
+                (CmdOP::Verbose.to(), None),
+                (CmdOP::Define.to(), Some("b=2")),
+                (CmdOP::Output.to(), Some("o3")),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokens_occurrence_error() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Define = 1,
+            Input,
+            Output,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'D', lon: "define",  has: Arg::Yes, num: CmdOP::Define.to(), },
+            Opt { sho: b'i', lon: "input",   has: Arg::Yes, num: CmdOP::Input.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'D',0),(b'i',1),(b'o',2),(b'v',3)];
+
+        #[rustfmt::skip]
+        let occurrence_ary = [
+            (CmdOP::Define.to(),  Occurrence::Accumulate),
+            (CmdOP::Input.to(),   Occurrence::First),
+            (CmdOP::Output.to(),  Occurrence::Last),
+            (CmdOP::Verbose.to(), Occurrence::Error),
+        ];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).occurrence(&occurrence_ary)
+        };
+        let args = vec!["-v", "-o", "o1", "--verbose"];
+        match lex.tokens_from(&args) {
+            Ok(t) => {
+                assert_eq!(format!("{:?}", t), "");
+                unreachable!();
+            }
+            Err(e) => {
+                #[cfg(feature = "single_error")]
+                assert_eq!(e.kind(), OptParseErrorKind::RepeatedOption);
+                #[cfg(not(feature = "single_error"))]
+                {
+                    assert_eq!(e.iter().count(), 1);
+                    let e = e.iter().next().unwrap();
+                    assert_eq!(e.kind(), OptParseErrorKind::RepeatedOption);
+                }
+                assert_eq!(format!("{}", e), "Repeated option: verbose");
+            }
+        }
+        //
+        // without the policy
+        let lex = Lex::create_with(&opt_ary, &opt_ary_sho_idx);
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals.len(), 3);
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn tokens_occurrence_events() {
+        use flood_tide::Event;
+        let args = vec!["-o", "o1", "-i", "i1", "-o", "o2", "-i", "i2"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Define = 1,
+            Input,
+            Output,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'D', lon: "define",  has: Arg::Yes, num: CmdOP::Define.to(), },
+            Opt { sho: b'i', lon: "input",   has: Arg::Yes, num: CmdOP::Input.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'D',0),(b'i',1),(b'o',2),(b'v',3)];
+
+        #[rustfmt::skip]
+        let occurrence_ary = [
+            (CmdOP::Define.to(),  Occurrence::Accumulate),
+            (CmdOP::Input.to(),   Occurrence::First),
+            (CmdOP::Output.to(),  Occurrence::Last),
+            (CmdOP::Verbose.to(), Occurrence::Error),
+        ];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).occurrence(&occurrence_ary)
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        let thing: Vec<(OptNum, Option<&str>)> = tokens
+            .events
+            .iter()
+            .filter_map(|ev| match ev {
+                Event::NameVal(nv) => Some((nv.opt.num, nv.val)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(thing, nums_vals(&tokens));
+        assert_eq!(
+            thing,
+            vec![
+                (CmdOP::Input.to(), Some("i1")),
+                (CmdOP::Output.to(), Some("o2"))
+            ]
+        );
+    }
+
+    #[cfg(feature = "stop_at_mm")]
+    mod argparse {
+        use flood_tide::{argparse, Arg};
+
+        argparse! {
+            pub struct MyConf {
+                (help,    bool,        b'h', "help",    Arg::No,  "display help", @help),
+                (define,  Vec<String>, b'D', "define",  Arg::Yes, "define the macro", @accumulate),
+                (input,   String,      b'i', "input",   Arg::Yes, "input file", @first),
+                (output,  String,      b'o', "output",  Arg::Yes, "output file", @last),
+                (verbose, bool,        b'v', "verbose", Arg::No,  "verbose mode", @once),
+            }
+        }
+
+        #[test]
+        fn parse_occurrence() {
+            #[rustfmt::skip]
+            let args = [
+                "-i", "i1", "-D", "a", "-o", "o1", "-i", "i2", "-D", "b", "-o", "o2", "-v", "f1",
+            ];
+            let conf = MyConf::parse(&args).unwrap();
+            assert_eq!(conf.define, vec!["a".to_string(), "b".to_string()]);
+            assert_eq!(conf.input, "i1");
+            assert_eq!(conf.output, "o2");
+            assert!(conf.verbose);
+            assert_eq!(conf.arg_params, vec!["f1".to_string()]);
+            //
+            assert!(MyConf::parse(&["-v", "-v"]).is_err());
+            #[cfg(feature = "help_version")]
+            {
+                use flood_tide::HelpVersion;
+                assert!(MyConf::parse(&["-v", "-v", "--help"]).unwrap().is_help());
+            }
+        }
+    }
+}
@@ -0,0 +1,295 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(not(feature = "single_error"))]
+#[cfg(feature = "option_argument")]
+#[cfg(not(feature = "long_only"))]
+mod test_partial_tokens {
+    use flood_tide::check;
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+    use flood_tide::OptParseErrorKind;
+
+    #[test]
+    fn tokens_partial() {
+        let args = vec!["-q", "--bogus", "-x", "--verbose=1", "-o", "o1", "f1", "f2"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Output = 1,
+            Quiet,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'q', lon: "quiet",   has: Arg::No,  num: CmdOP::Quiet.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',0),(b'q',1),(b'v',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let partial = lex.tokens_partial_from(&args);
+        let kinds: Vec<OptParseErrorKind> = partial.errs.iter().map(|e| e.kind()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                OptParseErrorKind::InvalidOption,
+                OptParseErrorKind::InvalidOption,
+                OptParseErrorKind::UnexpectedOptionArgument,
+            ]
+        );
+        let nums: Vec<OptNum> = partial
+            .tokens
+            .namevals
+            .iter()
+            .map(|nv| nv.opt.num)
+            .collect();
+        assert_eq!(nums, vec![CmdOP::Quiet.to(), CmdOP::Output.to()]);
+        assert_eq!(partial.tokens.namevals[1].val, Some("o1"));
+        assert_eq!(partial.tokens.free, vec!["f1", "f2"]);
+        //
+        let errs = partial.into_result().unwrap_err();
+        assert_eq!(errs, lex.tokens_from(&args).unwrap_err());
+    }
+
+    #[test]
+    fn tokens_partial_ok() {
+        let args = vec!["-q", "f1"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Output = 1,
+            Quiet,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'q', lon: "quiet",   has: Arg::No,  num: CmdOP::Quiet.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',0),(b'q',1),(b'v',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let partial = lex.tokens_partial_from(&args);
+        assert!(partial.errs.is_empty());
+        let tokens = partial.into_result().unwrap();
+        assert_eq!(tokens.namevals.len(), 1);
+        assert_eq!(tokens.free, vec!["f1"]);
+    }
+
+    #[test]
+    fn tokens_partial_missing_argument() {
+        let args = vec!["-q", "--output"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Output = 1,
+            Quiet,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'q', lon: "quiet",   has: Arg::No,  num: CmdOP::Quiet.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',0),(b'q',1),(b'v',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let partial = lex.tokens_partial_from(&args);
+        assert_eq!(partial.errs.len(), 1);
+        assert_eq!(
+            partial.errs.iter().next().unwrap().kind(),
+            OptParseErrorKind::MissingOptionArgument
+        );
+        assert_eq!(partial.tokens.namevals.len(), 1);
+        assert_eq!(partial.tokens.namevals[0].opt.num, CmdOP::Quiet.to());
+    }
+
+    #[cfg(feature = "subcommand")]
+    #[test]
+    fn tokens_partial_subcmd() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Output = 1,
+            Quiet,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'q', lon: "quiet",   has: Arg::No,  num: CmdOP::Quiet.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',0),(b'q',1),(b'v',2)];
+
+        let subcmd_ary = ["add", "list"];
+
+        let main_lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).subcmd(&subcmd_ary)
+        };
+        let args = vec!["-v", "bogus", "f1"];
+        let partial = main_lex.tokens_partial_from(&args);
+        assert_eq!(partial.errs.len(), 1);
+        assert_eq!(partial.tokens.namevals.len(), 1);
+        assert!(partial.tokens.subcmd.is_none());
+        //
+        let args = vec!["-x", "list"];
+        let partial = main_lex.tokens_partial_from(&args);
+        assert_eq!(partial.errs.len(), 1);
+        assert_eq!(partial.tokens.subcmd, Some("list"));
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn tokens_partial_events() {
+        use flood_tide::Event;
+        let args = vec!["-q", "--bogus", "f1"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Output = 1,
+            Quiet,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'q', lon: "quiet",   has: Arg::No,  num: CmdOP::Quiet.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',0),(b'q',1),(b'v',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let partial = lex.tokens_partial_from(&args);
+        assert_eq!(partial.errs.len(), 1);
+        assert_eq!(partial.tokens.events.len(), 2);
+        assert!(matches!(partial.tokens.events[0], Event::NameVal(_)));
+        assert!(matches!(partial.tokens.events[1], Event::Free("f1")));
+    }
+
+    #[cfg(feature = "help_version")]
+    #[test]
+    fn tokens_partial_help_version() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Output = 1,
+            Quiet,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'q', lon: "quiet",   has: Arg::No,  num: CmdOP::Quiet.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',0),(b'q',1),(b'v',2)];
+
+        let help_version_nums = [CmdOP::Quiet.to()];
+
+        let hv_lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).help_version(&help_version_nums)
+        };
+        let args = vec!["--bogus", "-q"];
+        let partial = hv_lex.tokens_partial_from(&args);
+        assert!(partial.errs.is_empty());
+        assert_eq!(partial.tokens.namevals.len(), 1);
+    }
+}
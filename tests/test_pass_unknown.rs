@@ -0,0 +1,226 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "pass_unknown")]
+mod test_pass_unknown {
+    use flood_tide::check;
+    #[cfg(feature = "option_argument")]
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_pass_unknown() {
+        #[rustfmt::skip]
+        let args = vec![
+            "-a", "--foo=bar", "-xby", "--barn", "--zoo", "free1",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            A = 1,
+            Barn = 2,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "",     has: Arg::No, num: CmdOP::A.to(), },
+            Opt { sho: b'b', lon: "barn", has: Arg::No, num: CmdOP::Barn.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "",     num: CmdOP::A.to(), },
+            Opt { sho: b'b', lon: "barn", num: CmdOP::Barn.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'b',1)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).pass_unknown()
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        assert_eq!(tokens.namevals.len(), 3);
+        assert_eq_tokens_namevals!(tokens, 0, b'a', "", None, CmdOP::A);
+        assert_eq_tokens_namevals!(tokens, 1, b'b', "barn", None, CmdOP::Barn);
+        assert_eq_tokens_namevals!(tokens, 2, b'b', "barn", None, CmdOP::Barn);
+        assert_eq!(tokens.unknown, vec!["--foo=bar", "-x", "-y", "--zoo"]);
+        assert_eq!(tokens.free, vec!["free1"]);
+    }
+
+    #[cfg(not(feature = "long_only"))]
+    #[cfg(feature = "option_argument")]
+    #[test]
+    fn tokens_pass_unknown_errors() {
+        // the known option is still checked
+        let args = vec!["--foo", "--barn=1"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Barn = 1,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'b', lon: "barn", has: Arg::No, num: CmdOP::Barn.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'b',0)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).pass_unknown()
+        };
+        match lex.tokens_from(&args) {
+            Ok(t) => {
+                assert_eq!(format!("{:?}", t), "");
+                unreachable!();
+            }
+            Err(e) => {
+                assert_eq!(format!("{}", e), "Unexpected option argument: barn: 1");
+            }
+        };
+    }
+
+    #[test]
+    fn tokens_pass_unknown_not_setup() {
+        // the unknown options are the errors without `Lex::pass_unknown()`
+        let args = vec!["-a", "-x"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            A = 1,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "", has: Arg::No, num: CmdOP::A.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "", num: CmdOP::A.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        match lex.tokens_from(&args) {
+            Ok(t) => {
+                assert_eq!(format!("{:?}", t), "");
+                unreachable!();
+            }
+            Err(e) => {
+                assert_eq!(format!("{}", e), "Invalid option: x");
+            }
+        };
+    }
+
+    #[cfg(feature = "long_only")]
+    #[test]
+    fn tokens_long_only_pass_unknown() {
+        #[rustfmt::skip]
+        let args = vec![
+            "-a", "-x", "-foo=bar", "-barn", "-zoo", "free1",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            A = 1,
+            Barn = 2,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "",     has: Arg::No, num: CmdOP::A.to(), },
+            Opt { sho: b'b', lon: "barn", has: Arg::No, num: CmdOP::Barn.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "",     num: CmdOP::A.to(), },
+            Opt { sho: b'b', lon: "barn", num: CmdOP::Barn.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'b',1)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).pass_unknown()
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        assert_eq!(tokens.namevals.len(), 2);
+        assert_eq_tokens_namevals!(tokens, 0, b'a', "", None, CmdOP::A);
+        assert_eq_tokens_namevals!(tokens, 1, b'b', "barn", None, CmdOP::Barn);
+        assert_eq!(tokens.unknown, vec!["-x", "-foo=bar", "-zoo"]);
+        assert_eq!(tokens.free, vec!["free1"]);
+    }
+}
@@ -0,0 +1,318 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "passthrough")]
+#[cfg(feature = "option_argument")]
+#[cfg(not(feature = "long_only"))]
+mod test_passthrough {
+    use flood_tide::check;
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+
+    #[cfg(not(feature = "stop_at_free"))]
+    #[test]
+    fn tokens_passthrough() {
+        let args = vec!["-v", "f1", "--", "-v", "--", "f2"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Help = 1,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'h', lon: "help",    has: Arg::No, num: CmdOP::Help.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No, num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'h',0),(b'v',1)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+        assert_eq!(tokens.namevals.len(), 1);
+        // `--` after the operand is left in `free`
+        assert!(!tokens.double_m);
+        assert_eq!(tokens.free, vec!["f1", "--", "-v", "--", "f2"]);
+        assert_eq!(tokens.mm_index, Some(2));
+        assert_eq!(tokens.before_mm(), ["f1"]);
+        assert_eq!(tokens.after_mm(), ["-v", "--", "f2"]);
+        assert_eq!(tokens.trailing(), vec!["f1", "-v", "--", "f2"]);
+    }
+
+    #[test]
+    fn tokens_passthrough_leading() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Help = 1,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'h', lon: "help",    has: Arg::No, num: CmdOP::Help.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No, num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'h',0),(b'v',1)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let args = vec!["-v", "--"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert!(tokens.double_m);
+        assert!(tokens.free.is_empty());
+        assert_eq!(tokens.mm_index, Some(0));
+        assert!(tokens.after_mm().is_empty());
+        //
+        let args = vec!["-v", "--", "f1"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert!(tokens.double_m);
+        assert_eq!(tokens.free, vec!["f1"]);
+        assert!(tokens.before_mm().is_empty());
+        assert_eq!(tokens.after_mm(), ["f1"]);
+        //
+        let args = vec!["-v", "f1"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert!(!tokens.double_m);
+        assert_eq!(tokens.free, vec!["f1"]);
+        assert_eq!(tokens.mm_index, None);
+        assert_eq!(tokens.before_mm(), ["f1"]);
+        assert!(tokens.after_mm().is_empty());
+    }
+
+    #[cfg(feature = "stop_at_free")]
+    #[test]
+    fn tokens_passthrough_stop_at_free() {
+        // `--` after the free argument did not end the options
+        let args = vec!["-v", "f1", "-v", "--", "y"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Verbose = 1,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'v', lon: "verbose", has: Arg::No, num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'v',0)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals.len(), 1);
+        assert!(!tokens.double_m);
+        assert_eq!(tokens.free, vec!["f1", "-v", "--", "y"]);
+        assert_eq!(tokens.mm_index, None);
+        assert_eq!(tokens.before_mm(), ["f1", "-v", "--", "y"]);
+        assert!(tokens.after_mm().is_empty());
+        assert_eq!(tokens.trailing(), vec!["f1", "-v", "--", "y"]);
+    }
+
+    #[cfg(feature = "subcommand")]
+    #[test]
+    fn tokens_passthrough_subcmd() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Help = 1,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'h', lon: "help",    has: Arg::No, num: CmdOP::Help.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No, num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'h',0),(b'v',1)];
+
+        let subcmd_ary = ["exec", "list"];
+
+        let main_lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).subcmd(&subcmd_ary)
+        };
+        let args = vec!["-v", "exec", "-v", "--", "ls", "-l"];
+        let tokens = main_lex.tokens_from(&args).unwrap();
+        assert_eq_tokens_subcmd!(tokens, "exec");
+        assert!(!tokens.double_m);
+        assert_eq!(tokens.trailing(), vec!["-v", "--", "ls", "-l"]);
+        // `--` of the subcommand is forwarded
+        assert_eq!(tokens.mm_index, None);
+        assert_eq!(tokens.before_mm(), ["-v", "--", "ls", "-l"]);
+        assert!(tokens.after_mm().is_empty());
+        //
+        // the options of the subcommand and the trailing arguments
+        let sub_args = tokens.trailing();
+        let sub_lex = Lex::create_with(&opt_ary, &opt_ary_sho_idx).subcmd(&[]);
+        let sub_tokens = sub_lex.tokens_from(&sub_args).unwrap();
+        assert_eq!(sub_tokens.namevals.len(), 1);
+        assert_eq!(sub_tokens.trailing(), vec!["ls", "-l"]);
+        //
+        // the subcommand is not after `--`
+        let args = vec!["-v", "--", "exec"];
+        let tokens = main_lex.tokens_from(&args).unwrap();
+        assert!(tokens.subcmd.is_none());
+        assert_eq!(tokens.free, vec!["exec"]);
+        assert_eq!(tokens.after_mm(), ["exec"]);
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn tokens_passthrough_events() {
+        use flood_tide::Event;
+        let args = vec!["-v", "--", "-h"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Help = 1,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'h', lon: "help",    has: Arg::No, num: CmdOP::Help.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No, num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'h',0),(b'v',1)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.events.len(), 3);
+        assert!(matches!(tokens.events[1], Event::DoubleM));
+        assert!(matches!(tokens.events[2], Event::Free("-h")));
+        assert_eq!(tokens.after_mm(), ["-h"]);
+    }
+
+    #[test]
+    fn parse_simple_gnu_style_passthrough() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Help = 1,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'h', lon: "help",    has: Arg::No, num: CmdOP::Help.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No, num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'h',0),(b'v',1)];
+
+        #[derive(Default)]
+        struct Conf {
+            verbose: bool,
+        }
+        impl flood_tide::HelpVersion for Conf {
+            fn is_help(&self) -> bool {
+                false
+            }
+            fn is_version(&self) -> bool {
+                false
+            }
+        }
+        let args = vec!["-v", "--", "-h", "f1"];
+        let mut conf = Conf::default();
+        let (free, res) = flood_tide::parse_simple_gnu_style(
+            &mut conf,
+            &opt_ary,
+            &opt_ary_sho_idx,
+            &args,
+            |c, nv| {
+                if nv.opt.num == CmdOP::Verbose.to() {
+                    c.verbose = true;
+                }
+                Ok(())
+            },
+        );
+        assert!(res.is_ok());
+        assert!(conf.verbose);
+        assert_eq!(free, Some(vec!["-h".to_string(), "f1".to_string()]));
+    }
+}
@@ -0,0 +1,386 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "secondary_prefix")]
+mod test_secondary_prefix {
+    use flood_tide::check;
+    #[cfg(feature = "option_argument")]
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+
+    #[cfg(not(feature = "long_only"))]
+    #[cfg(feature = "option_argument")]
+    #[test]
+    fn tokens_secondary_prefix_short() {
+        let args = vec!["-ex", "+ex", "+o", "pipefail", "++verbose", "-v"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            E = 1,
+            O,
+            Verbose,
+            X,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'e', lon: "",        has: Arg::No,  num: CmdOP::E.to(), },
+            Opt { sho: b'o', lon: "",        has: Arg::Yes, num: CmdOP::O.to(), },
+            Opt { sho: b'x', lon: "",        has: Arg::No,  num: CmdOP::X.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'e', lon: "",        num: CmdOP::E.to(), },
+            Opt { sho: b'o', lon: "",        num: CmdOP::O.to(), },
+            Opt { sho: b'x', lon: "",        num: CmdOP::X.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'e',0),(b'o',1),(b'v',3),(b'x',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).secondary_prefix(b'+')
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+        let thing: Vec<(OptNum, u8)> = tokens
+            .namevals
+            .iter()
+            .map(|nv| (nv.opt.num, nv.prefix))
+            .collect();
+        assert_eq!(
+            thing,
+            vec![
+                (CmdOP::E.to(), b'-'),
+                (CmdOP::X.to(), b'-'),
+                (CmdOP::E.to(), b'+'),
+                (CmdOP::X.to(), b'+'),
+                (CmdOP::O.to(), b'+'),
+                (CmdOP::Verbose.to(), b'+'),
+                (CmdOP::Verbose.to(), b'-'),
+            ]
+        );
+        assert_eq!(tokens.namevals[4].val, Some("pipefail"));
+        assert!(tokens.free.is_empty());
+    }
+
+    #[cfg(feature = "long_only")]
+    #[test]
+    fn tokens_secondary_prefix_long_only() {
+        let args = vec!["+verbose", "-x", "+x"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            E = 1,
+            O,
+            Verbose,
+            X,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'e', lon: "",        has: Arg::No,  num: CmdOP::E.to(), },
+            Opt { sho: b'o', lon: "",        has: Arg::Yes, num: CmdOP::O.to(), },
+            Opt { sho: b'x', lon: "",        has: Arg::No,  num: CmdOP::X.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'e', lon: "",        num: CmdOP::E.to(), },
+            Opt { sho: b'o', lon: "",        num: CmdOP::O.to(), },
+            Opt { sho: b'x', lon: "",        num: CmdOP::X.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'e',0),(b'o',1),(b'v',3),(b'x',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).secondary_prefix(b'+')
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        let thing: Vec<(OptNum, u8)> = tokens
+            .namevals
+            .iter()
+            .map(|nv| (nv.opt.num, nv.prefix))
+            .collect();
+        assert_eq!(
+            thing,
+            vec![
+                (CmdOP::Verbose.to(), b'+'),
+                (CmdOP::X.to(), b'-'),
+                (CmdOP::X.to(), b'+'),
+            ]
+        );
+    }
+
+    #[cfg(not(feature = "stop_at_free"))]
+    #[test]
+    fn tokens_secondary_prefix_free() {
+        let args = vec!["+", "free1", "-x"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            E = 1,
+            O,
+            Verbose,
+            X,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'e', lon: "",        has: Arg::No,  num: CmdOP::E.to(), },
+            Opt { sho: b'o', lon: "",        has: Arg::Yes, num: CmdOP::O.to(), },
+            Opt { sho: b'x', lon: "",        has: Arg::No,  num: CmdOP::X.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'e', lon: "",        num: CmdOP::E.to(), },
+            Opt { sho: b'o', lon: "",        num: CmdOP::O.to(), },
+            Opt { sho: b'x', lon: "",        num: CmdOP::X.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'e',0),(b'o',1),(b'v',3),(b'x',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).secondary_prefix(b'+')
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals.len(), 1);
+        assert_eq!(tokens.free, vec!["+", "free1"]);
+        //
+        // without the secondary prefix
+        let lex = Lex::create_with(&opt_ary, &opt_ary_sho_idx);
+        let args = vec!["+x", "-x"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals.len(), 1);
+        assert_eq!(tokens.namevals[0].prefix, b'-');
+        assert_eq!(tokens.free, vec!["+x"]);
+    }
+
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_secondary_prefix_invalid() {
+        let args = vec!["+q"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            E = 1,
+            O,
+            Verbose,
+            X,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'e', lon: "",        has: Arg::No,  num: CmdOP::E.to(), },
+            Opt { sho: b'o', lon: "",        has: Arg::Yes, num: CmdOP::O.to(), },
+            Opt { sho: b'x', lon: "",        has: Arg::No,  num: CmdOP::X.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'e', lon: "",        num: CmdOP::E.to(), },
+            Opt { sho: b'o', lon: "",        num: CmdOP::O.to(), },
+            Opt { sho: b'x', lon: "",        num: CmdOP::X.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'e',0),(b'o',1),(b'v',3),(b'x',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).secondary_prefix(b'+')
+        };
+        match lex.tokens_from(&args) {
+            Ok(_) => unreachable!(),
+            Err(e) => assert_eq!(format!("{}", e), "Invalid option: q"),
+        }
+    }
+
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_secondary_prefix_lone() {
+        // the lone `++` is the free argument, not the empty long name
+        let args = vec!["-x", "++"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            E = 1,
+            O,
+            Verbose,
+            X,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'e', lon: "",        has: Arg::No,  num: CmdOP::E.to(), },
+            Opt { sho: b'o', lon: "",        has: Arg::Yes, num: CmdOP::O.to(), },
+            Opt { sho: b'x', lon: "",        has: Arg::No,  num: CmdOP::X.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'e', lon: "",        num: CmdOP::E.to(), },
+            Opt { sho: b'o', lon: "",        num: CmdOP::O.to(), },
+            Opt { sho: b'x', lon: "",        num: CmdOP::X.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'e',0),(b'o',1),(b'v',3),(b'x',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).secondary_prefix(b'+')
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals.len(), 1);
+        assert_eq!(tokens.namevals[0].opt.num, CmdOP::X.to());
+        assert_eq!(tokens.free, vec!["++"]);
+        //
+        let args = vec!["++=x"];
+        match lex.tokens_from(&args) {
+            Ok(_) => unreachable!(),
+            Err(e) => assert_eq!(format!("{}", e), "Invalid option: =x"),
+        }
+        let args = vec!["--=x"];
+        match lex.tokens_from(&args) {
+            Ok(_) => unreachable!(),
+            Err(e) => assert_eq!(format!("{}", e), "Invalid option: =x"),
+        }
+    }
+
+    #[cfg(not(feature = "long_only"))]
+    #[cfg(feature = "pass_unknown")]
+    #[test]
+    fn tokens_secondary_prefix_unknown() {
+        let args = vec!["+qx", "++unknown", "-q"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            E = 1,
+            O,
+            Verbose,
+            X,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'e', lon: "",        has: Arg::No,  num: CmdOP::E.to(), },
+            Opt { sho: b'o', lon: "",        has: Arg::Yes, num: CmdOP::O.to(), },
+            Opt { sho: b'x', lon: "",        has: Arg::No,  num: CmdOP::X.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'e', lon: "",        num: CmdOP::E.to(), },
+            Opt { sho: b'o', lon: "",        num: CmdOP::O.to(), },
+            Opt { sho: b'x', lon: "",        num: CmdOP::X.to(), },
+            Opt { sho: b'v', lon: "verbose", num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'e',0),(b'o',1),(b'v',3),(b'x',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+                .secondary_prefix(b'+')
+                .pass_unknown()
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals.len(), 1);
+        assert_eq!(tokens.namevals[0].prefix, b'+');
+        assert_eq!(tokens.unknown, vec!["+q", "++unknown", "-q"]);
+    }
+}
@@ -0,0 +1,193 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "stream_spec")]
+#[cfg(feature = "option_argument")]
+mod test_stream_spec {
+    use flood_tide::check;
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_stream_spec() {
+        #[rustfmt::skip]
+        let args = vec![
+            "--codec:v=copy", "--codec:a", "aac", "--bitrate:a:0=64k", "-c", "h264", "--codec=mp3",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Bitrate = 1,
+            Codec,
+            Map,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'b', lon: "bitrate", has: Arg::Yes, num: CmdOP::Bitrate.to(), },
+            Opt { sho: b'c', lon: "codec",   has: Arg::Yes, num: CmdOP::Codec.to(), },
+            Opt { sho: b'm', lon: "map",     has: Arg::Yes, num: CmdOP::Map.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'b',0),(b'c',1),(b'm',2)];
+
+        let stream_spec_nums = [CmdOP::Bitrate.to(), CmdOP::Codec.to()];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).stream_spec(&stream_spec_nums)
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        assert_eq!(tokens.namevals.len(), 5);
+        assert_eq_tokens_namevals!(tokens, 0, b'c', "codec", Some("copy"), CmdOP::Codec);
+        assert_eq_tokens_namevals!(tokens, 1, b'c', "codec", Some("aac"), CmdOP::Codec);
+        assert_eq_tokens_namevals!(tokens, 2, b'b', "bitrate", Some("64k"), CmdOP::Bitrate);
+        assert_eq_tokens_namevals!(tokens, 3, b'c', "codec", Some("h264"), CmdOP::Codec);
+        assert_eq_tokens_namevals!(tokens, 4, b'c', "codec", Some("mp3"), CmdOP::Codec);
+        let thing: Vec<Option<&str>> = tokens.namevals.iter().map(|nv| nv.spec).collect();
+        assert_eq!(thing, vec![Some("v"), Some("a"), Some("a:0"), None, None]);
+    }
+
+    #[cfg(feature = "long_only")]
+    #[test]
+    fn tokens_stream_spec_long_only() {
+        #[rustfmt::skip]
+        let args = vec![
+            "-c:v", "copy", "-b:a", "64k", "-codec:a=aac", "-c", "h264",
+        ];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Bitrate = 1,
+            Codec,
+            Map,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'b', lon: "bitrate", has: Arg::Yes, num: CmdOP::Bitrate.to(), },
+            Opt { sho: b'c', lon: "codec",   has: Arg::Yes, num: CmdOP::Codec.to(), },
+            Opt { sho: b'm', lon: "map",     has: Arg::Yes, num: CmdOP::Map.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'b',0),(b'c',1),(b'm',2)];
+
+        let stream_spec_nums = [CmdOP::Bitrate.to(), CmdOP::Codec.to()];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).stream_spec(&stream_spec_nums)
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        assert_eq!(tokens.namevals.len(), 4);
+        assert_eq_tokens_namevals!(tokens, 0, b'c', "codec", Some("copy"), CmdOP::Codec);
+        assert_eq_tokens_namevals!(tokens, 1, b'b', "bitrate", Some("64k"), CmdOP::Bitrate);
+        assert_eq_tokens_namevals!(tokens, 2, b'c', "codec", Some("aac"), CmdOP::Codec);
+        assert_eq_tokens_namevals!(tokens, 3, b'c', "codec", Some("h264"), CmdOP::Codec);
+        let thing: Vec<Option<&str>> = tokens.namevals.iter().map(|nv| nv.spec).collect();
+        assert_eq!(thing, vec![Some("v"), Some("a"), Some("a"), None]);
+        #[cfg(feature = "was_long")]
+        {
+            let thing: Vec<bool> = tokens.namevals.iter().map(|nv| nv.was_long).collect();
+            assert_eq!(thing, vec![false, false, true, false]);
+        }
+    }
+
+    #[test]
+    fn tokens_stream_spec_invalid() {
+        #[cfg(not(feature = "long_only"))]
+        let args = vec!["--map:v", "0", "--codec:", "copy"];
+        #[cfg(feature = "long_only")]
+        let args = vec!["-map:v", "0", "-codec:", "copy"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Bitrate = 1,
+            Codec,
+            Map,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'b', lon: "bitrate", has: Arg::Yes, num: CmdOP::Bitrate.to(), },
+            Opt { sho: b'c', lon: "codec",   has: Arg::Yes, num: CmdOP::Codec.to(), },
+            Opt { sho: b'm', lon: "map",     has: Arg::Yes, num: CmdOP::Map.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'b',0),(b'c',1),(b'm',2)];
+
+        let stream_spec_nums = [CmdOP::Bitrate.to(), CmdOP::Codec.to()];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).stream_spec(&stream_spec_nums)
+        };
+        match lex.tokens_from(&args) {
+            Ok(t) => {
+                assert_eq!(format!("{:?}", t), "");
+                unreachable!();
+            }
+            Err(e) => {
+                // `0` is the free argument at the compiling with feature = "stop_at_free"
+                #[cfg(any(feature = "single_error", feature = "stop_at_free"))]
+                assert_eq!(format!("{}", e), "Invalid option: map:v");
+                #[cfg(not(any(feature = "single_error", feature = "stop_at_free")))]
+                assert_eq!(
+                    format!("{}", e),
+                    "Invalid option: map:v\nInvalid option: codec:"
+                );
+            }
+        };
+    }
+}
@@ -0,0 +1,188 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "strict_value")]
+mod test_strict_value {
+    use flood_tide::check;
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+    use flood_tide::OptParseErrorKind;
+    use flood_tide::StrictValue;
+
+    fn long(name: &str) -> String {
+        #[cfg(not(feature = "long_only"))]
+        let r = format!("--{}", name);
+        #[cfg(feature = "long_only")]
+        let r = format!("-{}", name);
+        r
+    }
+
+    #[test]
+    fn tokens_strict_value_long() {
+        let output = long("output");
+        let verbose = long("verbose");
+        let args = vec![output.as_str(), verbose.as_str()];
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Input = 1,
+            Output,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'i', lon: "input",   has: Arg::Yes, num: CmdOP::Input.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'i',0),(b'o',1),(b'v',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).strict_value(StrictValue::default())
+        };
+        match lex.tokens_from(&args) {
+            Ok(t) => {
+                assert_eq!(format!("{:?}", t), "");
+                unreachable!();
+            }
+            Err(e) => {
+                #[cfg(feature = "single_error")]
+                assert_eq!(e.kind(), OptParseErrorKind::MissingOptionArgument);
+                #[cfg(not(feature = "single_error"))]
+                {
+                    assert_eq!(e.iter().count(), 1);
+                    let e = e.iter().next().unwrap();
+                    assert_eq!(e.kind(), OptParseErrorKind::MissingOptionArgument);
+                }
+                assert_eq!(format!("{}", e), "Missing option argument: output");
+            }
+        }
+        //
+        // the attached argument
+        let output_eq = long("output=--verbose");
+        let args = vec![output_eq.as_str()];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals[0].val, Some("--verbose"));
+    }
+
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_strict_value_short() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Input = 1,
+            Output,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'i', lon: "input",   has: Arg::Yes, num: CmdOP::Input.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'i',0),(b'o',1),(b'v',2)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).strict_value(StrictValue::default())
+        };
+        for arg in ["-v", "-", "-5"] {
+            let args = vec!["-o", arg];
+            assert!(lex.tokens_from(&args).is_err(), "{}", arg);
+        }
+        let args = vec!["-o", "out", "-o-x"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        let thing: Vec<Option<&str>> = tokens.namevals.iter().map(|nv| nv.val).collect();
+        assert_eq!(thing, vec![Some("out"), Some("-x")]);
+    }
+
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_strict_value_policy() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Input = 1,
+            Output,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'i', lon: "input",   has: Arg::Yes, num: CmdOP::Input.to(), },
+            Opt { sho: b'o', lon: "output",  has: Arg::Yes, num: CmdOP::Output.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'i',0),(b'o',1),(b'v',2)];
+
+        let strict_nums = [CmdOP::Output.to()];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).strict_value(StrictValue {
+                nums: &strict_nums,
+                lone_minus: true,
+                negative_number: true,
+            })
+        };
+        let args = vec!["-o", "-", "-o", "-5", "-o", "-1.5", "-i", "-v"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        let thing: Vec<(OptNum, Option<&str>)> = tokens
+            .namevals
+            .iter()
+            .map(|nv| (nv.opt.num, nv.val))
+            .collect();
+        assert_eq!(
+            thing,
+            vec![
+                (CmdOP::Output.to(), Some("-")),
+                (CmdOP::Output.to(), Some("-5")),
+                (CmdOP::Output.to(), Some("-1.5")),
+                (CmdOP::Input.to(), Some("-v")),
+            ]
+        );
+        //
+        let args = vec!["-o", "-v"];
+        assert!(lex.tokens_from(&args).is_err());
+    }
+}
@@ -100,4 +100,70 @@ mod test_subcmd {
             };
         }
     }
+
+    #[cfg(feature = "subcommand")]
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_subcommand_stdin() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            V = 1,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'v', lon: "", has: Arg::No, num: CmdOP::V.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'v', lon: "", num: CmdOP::V.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'v',0)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).subcmd(&["subcmd"])
+        };
+
+        // the lone `-` is not a subcommand
+        let args = vec!["-v", "-", "subcmd"];
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+        assert_eq_tokens_namevals!(tokens, 0, b'v', "", None, CmdOP::V);
+        assert_eq!(tokens.subcmd, None);
+        assert_eq_tokens_free!(tokens, 0, "-");
+        assert_eq_tokens_free!(tokens, 1, "subcmd");
+
+        let args = vec!["-v", "subcmd", "-"];
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+        assert_eq_tokens_namevals!(tokens, 0, b'v', "", None, CmdOP::V);
+        assert_eq_tokens_subcmd!(tokens, "subcmd");
+        assert_eq!(tokens.free.len(), 1);
+        assert_eq_tokens_free!(tokens, 0, "-");
+    }
 }
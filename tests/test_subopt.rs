@@ -0,0 +1,400 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "subopt")]
+mod test_subopt {
+    use flood_tide::check;
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+    use flood_tide::OptParseErrorKind;
+
+    // the sub-options, or the error kind and the message
+    type SubResult = Result<Vec<(OptNum, Option<String>)>, (OptParseErrorKind, String)>;
+
+    // the sub-options of `-o <val>`
+    fn subopts(lex: &Lex, sub_ary: &[Opt], val: &str) -> SubResult {
+        let args = ["-o", val];
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+        match tokens.namevals[0].subopts(sub_ary) {
+            Ok(subs) => Ok(subs
+                .iter()
+                .map(|nv| (nv.opt.num, nv.val.map(|s| s.to_string())))
+                .collect()),
+            Err(err) => Err((err.kind(), err.to_string())),
+        }
+    }
+
+    #[test]
+    fn subopt_basic() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Options = 1,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'o', lon: "",        has: Arg::Yes, num: CmdOP::Options.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',0),(b'v',1)];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum SubOP {
+            Noatime = 1,
+            Nodev,
+            Ro,
+            Uid,
+            Umask,
+        }
+
+        impl SubOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let sub_ary = [
+            Opt { sho: 0u8, lon: "noatime", has: Arg::No,    num: SubOP::Noatime.to(), },
+            Opt { sho: 0u8, lon: "nodev",   has: Arg::No,    num: SubOP::Nodev.to(), },
+            Opt { sho: 0u8, lon: "ro",      has: Arg::No,    num: SubOP::Ro.to(), },
+            Opt { sho: 0u8, lon: "uid",     has: Arg::Yes,   num: SubOP::Uid.to(), },
+            Opt { sho: 0u8, lon: "umask",   has: Arg::Maybe, num: SubOP::Umask.to(), },
+        ];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            assert!(check::validate(&sub_ary, &[]).is_empty());
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        assert_eq!(
+            subopts(&lex, &sub_ary, "ro,uid=1000,,noatime"),
+            Ok(vec![
+                (SubOP::Ro.to(), None),
+                (SubOP::Uid.to(), Some("1000".to_string())),
+                (SubOP::Noatime.to(), None),
+            ])
+        );
+        assert_eq!(
+            subopts(&lex, &sub_ary, "umask,umask=022,uid="),
+            Ok(vec![
+                (SubOP::Umask.to(), Some("".to_string())),
+                (SubOP::Umask.to(), Some("022".to_string())),
+                (SubOP::Uid.to(), Some("".to_string())),
+            ])
+        );
+        assert_eq!(subopts(&lex, &sub_ary, ""), Ok(vec![]));
+    }
+
+    #[test]
+    fn subopt_no_val() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Options = 1,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'o', lon: "",        has: Arg::Yes, num: CmdOP::Options.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',0),(b'v',1)];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum SubOP {
+            Noatime = 1,
+            Nodev,
+            Ro,
+            Uid,
+            Umask,
+        }
+
+        impl SubOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let sub_ary = [
+            Opt { sho: 0u8, lon: "noatime", has: Arg::No,    num: SubOP::Noatime.to(), },
+            Opt { sho: 0u8, lon: "nodev",   has: Arg::No,    num: SubOP::Nodev.to(), },
+            Opt { sho: 0u8, lon: "ro",      has: Arg::No,    num: SubOP::Ro.to(), },
+            Opt { sho: 0u8, lon: "uid",     has: Arg::Yes,   num: SubOP::Uid.to(), },
+            Opt { sho: 0u8, lon: "umask",   has: Arg::Maybe, num: SubOP::Umask.to(), },
+        ];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            assert!(check::validate(&sub_ary, &[]).is_empty());
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let args = ["-v"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert!(tokens.namevals[0].subopts(&sub_ary).unwrap().is_empty());
+    }
+
+    #[test]
+    fn subopt_error() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Options = 1,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'o', lon: "",        has: Arg::Yes, num: CmdOP::Options.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',0),(b'v',1)];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum SubOP {
+            Noatime = 1,
+            Nodev,
+            Ro,
+            Uid,
+            Umask,
+        }
+
+        impl SubOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let sub_ary = [
+            Opt { sho: 0u8, lon: "noatime", has: Arg::No,    num: SubOP::Noatime.to(), },
+            Opt { sho: 0u8, lon: "nodev",   has: Arg::No,    num: SubOP::Nodev.to(), },
+            Opt { sho: 0u8, lon: "ro",      has: Arg::No,    num: SubOP::Ro.to(), },
+            Opt { sho: 0u8, lon: "uid",     has: Arg::Yes,   num: SubOP::Uid.to(), },
+            Opt { sho: 0u8, lon: "umask",   has: Arg::Maybe, num: SubOP::Umask.to(), },
+        ];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            assert!(check::validate(&sub_ary, &[]).is_empty());
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let kind = OptParseErrorKind::InvalidOptionArgument;
+        assert_eq!(
+            subopts(&lex, &sub_ary, "ro,uid"),
+            Err((
+                kind.clone(),
+                "Invalid option argument: o: missing sub-option argument: uid".to_string()
+            ))
+        );
+        assert_eq!(
+            subopts(&lex, &sub_ary, "ro=1"),
+            Err((
+                kind.clone(),
+                "Invalid option argument: o: unexpected sub-option argument: ro=1".to_string()
+            ))
+        );
+        assert_eq!(
+            subopts(&lex, &sub_ary, "rw"),
+            Err((
+                kind,
+                "Invalid option argument: o: invalid sub-option: rw".to_string()
+            ))
+        );
+    }
+
+    #[cfg(feature = "abbreviate")]
+    #[test]
+    fn subopt_abbreviate() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Options = 1,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'o', lon: "",        has: Arg::Yes, num: CmdOP::Options.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',0),(b'v',1)];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum SubOP {
+            Noatime = 1,
+            Nodev,
+            Ro,
+            Uid,
+            Umask,
+        }
+
+        impl SubOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let sub_ary = [
+            Opt { sho: 0u8, lon: "noatime", has: Arg::No,    num: SubOP::Noatime.to(), },
+            Opt { sho: 0u8, lon: "nodev",   has: Arg::No,    num: SubOP::Nodev.to(), },
+            Opt { sho: 0u8, lon: "ro",      has: Arg::No,    num: SubOP::Ro.to(), },
+            Opt { sho: 0u8, lon: "uid",     has: Arg::Yes,   num: SubOP::Uid.to(), },
+            Opt { sho: 0u8, lon: "umask",   has: Arg::Maybe, num: SubOP::Umask.to(), },
+        ];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            assert!(check::validate(&sub_ary, &[]).is_empty());
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        assert_eq!(
+            subopts(&lex, &sub_ary, "noa,ui=0,um"),
+            Ok(vec![
+                (SubOP::Noatime.to(), None),
+                (SubOP::Uid.to(), Some("0".to_string())),
+                (SubOP::Umask.to(), Some("".to_string())),
+            ])
+        );
+        assert_eq!(
+            subopts(&lex, &sub_ary, "no"),
+            Err((
+                OptParseErrorKind::InvalidOptionArgument,
+                concat!(
+                    "Invalid option argument: o: ambiguous sub-option: no:",
+                    " possibilities: 'noatime' 'nodev'"
+                )
+                .to_string()
+            ))
+        );
+    }
+
+    #[cfg(not(feature = "abbreviate"))]
+    #[test]
+    fn subopt_not_abbreviate() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Options = 1,
+            Verbose,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'o', lon: "",        has: Arg::Yes, num: CmdOP::Options.to(), },
+            Opt { sho: b'v', lon: "verbose", has: Arg::No,  num: CmdOP::Verbose.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',0),(b'v',1)];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum SubOP {
+            Noatime = 1,
+            Nodev,
+            Ro,
+            Uid,
+            Umask,
+        }
+
+        impl SubOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let sub_ary = [
+            Opt { sho: 0u8, lon: "noatime", has: Arg::No,    num: SubOP::Noatime.to(), },
+            Opt { sho: 0u8, lon: "nodev",   has: Arg::No,    num: SubOP::Nodev.to(), },
+            Opt { sho: 0u8, lon: "ro",      has: Arg::No,    num: SubOP::Ro.to(), },
+            Opt { sho: 0u8, lon: "uid",     has: Arg::Yes,   num: SubOP::Uid.to(), },
+            Opt { sho: 0u8, lon: "umask",   has: Arg::Maybe, num: SubOP::Umask.to(), },
+        ];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            assert!(check::validate(&sub_ary, &[]).is_empty());
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        assert_eq!(
+            subopts(&lex, &sub_ary, "noa"),
+            Err((
+                OptParseErrorKind::InvalidOptionArgument,
+                "Invalid option argument: o: invalid sub-option: noa".to_string()
+            ))
+        );
+    }
+}
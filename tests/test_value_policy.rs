@@ -0,0 +1,244 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "value_policy")]
+mod test_value_policy {
+    use flood_tide::check;
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+    use flood_tide::ValuePolicy;
+
+    fn long(name: &str) -> String {
+        #[cfg(not(feature = "long_only"))]
+        let r = format!("--{}", name);
+        #[cfg(feature = "long_only")]
+        let r = format!("-{}", name);
+        r
+    }
+
+    fn err_string(lex: &Lex, args: &[&str]) -> String {
+        match lex.tokens_from(args) {
+            Ok(t) => format!("{:?}", t),
+            Err(e) => format!("{}", e),
+        }
+    }
+
+    #[test]
+    fn tokens_value_policy_long_equals_only() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            A = 1,
+            B,
+            Color,
+            Output,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "",       has: Arg::No,    num: CmdOP::A.to(), },
+            Opt { sho: b'b', lon: "",       has: Arg::No,    num: CmdOP::B.to(), },
+            Opt { sho: b'c', lon: "color",  has: Arg::Maybe, num: CmdOP::Color.to(), },
+            Opt { sho: b'o', lon: "output", has: Arg::Yes,   num: CmdOP::Output.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'b',1),(b'c',2),(b'o',3)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).value_policy(ValuePolicy {
+                long_equals_only: true,
+                ..Default::default()
+            })
+        };
+        let output_eq = long("output=out");
+        let args = vec![output_eq.as_str()];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals[0].val, Some("out"));
+        //
+        let output = long("output");
+        let args = vec![output.as_str(), "out"];
+        assert_eq!(err_string(&lex, &args), "Missing option argument: output");
+    }
+
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_value_policy_short_separate_only() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            A = 1,
+            B,
+            Color,
+            Output,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "",       has: Arg::No,    num: CmdOP::A.to(), },
+            Opt { sho: b'b', lon: "",       has: Arg::No,    num: CmdOP::B.to(), },
+            Opt { sho: b'c', lon: "color",  has: Arg::Maybe, num: CmdOP::Color.to(), },
+            Opt { sho: b'o', lon: "output", has: Arg::Yes,   num: CmdOP::Output.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'b',1),(b'c',2),(b'o',3)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).value_policy(ValuePolicy {
+                short_separate_only: true,
+                ..Default::default()
+            })
+        };
+        let args = vec!["-o", "out", "-ab"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals.len(), 3);
+        assert_eq!(tokens.namevals[0].val, Some("out"));
+        //
+        let args = vec!["-oout"];
+        assert_eq!(
+            err_string(&lex, &args),
+            "Unexpected option argument: o: out"
+        );
+        let args = vec!["-cauto"];
+        assert_eq!(
+            err_string(&lex, &args),
+            "Unexpected option argument: c: auto"
+        );
+        let args = vec!["-o=out"];
+        assert_eq!(
+            err_string(&lex, &args),
+            "Unexpected option argument: o: =out"
+        );
+    }
+
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_value_policy_no_cluster() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            A = 1,
+            B,
+            Color,
+            Output,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "",       has: Arg::No,    num: CmdOP::A.to(), },
+            Opt { sho: b'b', lon: "",       has: Arg::No,    num: CmdOP::B.to(), },
+            Opt { sho: b'c', lon: "color",  has: Arg::Maybe, num: CmdOP::Color.to(), },
+            Opt { sho: b'o', lon: "output", has: Arg::Yes,   num: CmdOP::Output.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'b',1),(b'c',2),(b'o',3)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).value_policy(ValuePolicy {
+                no_cluster: true,
+                ..Default::default()
+            })
+        };
+        let args = vec!["-a", "-b", "-oout"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals.len(), 3);
+        assert_eq!(tokens.namevals[2].val, Some("out"));
+        //
+        let args = vec!["-ab"];
+        assert_eq!(err_string(&lex, &args), "Invalid option: ab");
+    }
+
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn tokens_value_policy_short_strip_equals() {
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            A = 1,
+            B,
+            Color,
+            Output,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "",       has: Arg::No,    num: CmdOP::A.to(), },
+            Opt { sho: b'b', lon: "",       has: Arg::No,    num: CmdOP::B.to(), },
+            Opt { sho: b'c', lon: "color",  has: Arg::Maybe, num: CmdOP::Color.to(), },
+            Opt { sho: b'o', lon: "output", has: Arg::Yes,   num: CmdOP::Output.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'b',1),(b'c',2),(b'o',3)];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).value_policy(ValuePolicy {
+                short_strip_equals: true,
+                ..Default::default()
+            })
+        };
+        let args = vec!["-o=out", "-oout", "-c=", "-a"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        let thing: Vec<Option<&str>> = tokens.namevals.iter().map(|nv| nv.val).collect();
+        assert_eq!(thing, vec![Some("out"), Some("out"), Some(""), None]);
+        //
+        let lex = Lex::create_with(&opt_ary, &opt_ary_sho_idx).value_policy(ValuePolicy {
+            short_separate_only: true,
+            short_strip_equals: true,
+            ..Default::default()
+        });
+        let args = vec!["-o=out"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals[0].val, Some("out"));
+        let args = vec!["-oout"];
+        assert_eq!(
+            err_string(&lex, &args),
+            "Unexpected option argument: o: out"
+        );
+    }
+}
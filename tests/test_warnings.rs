@@ -0,0 +1,629 @@
+#[allow(unused_macros)]
+#[macro_use]
+mod helper;
+
+#[cfg(feature = "warnings")]
+mod test_warnings {
+    use flood_tide::check;
+    #[cfg(feature = "option_argument")]
+    use flood_tide::Arg;
+    use flood_tide::Lex;
+    use flood_tide::Opt;
+    use flood_tide::OptNum;
+    use flood_tide::OptParseWarning;
+    use flood_tide::OptParseWarningKind;
+
+    #[test]
+    fn tokens_warnings_deprecated() {
+        // the short name `-c` is deprecated, the long name `--color` is not
+        #[cfg(not(feature = "long_only"))]
+        let args = vec!["--color", "--colour", "-x", "--silent", "-q", "-c"];
+        #[cfg(feature = "long_only")]
+        let args = vec!["-color", "-colour", "-x", "-silent", "-q", "-c"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Quiet,
+            Silent,
+            X,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'x', lon: "",       has: Arg::No, num: CmdOP::X.to(), },
+            Opt { sho: b'c', lon: "color",  has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colour", has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: b'q', lon: "quiet",  has: Arg::No, num: CmdOP::Quiet.to(), },
+            Opt { sho: 0u8,  lon: "silent", has: Arg::No, num: CmdOP::Silent.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'x', lon: "",       num: CmdOP::X.to(), },
+            Opt { sho: b'c', lon: "color",  num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colour", num: CmdOP::Color.to(), },
+            Opt { sho: b'q', lon: "quiet",  num: CmdOP::Quiet.to(), },
+            Opt { sho: 0u8,  lon: "silent", num: CmdOP::Silent.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'c',1),(b'q',3),(b'x',0)];
+
+        #[rustfmt::skip]
+        #[cfg(not(feature = "long_only"))]
+        let deprecated_ary = [
+            ("--colour", "use --color"),
+            ("--silent", ""),
+            ("-c", "use --color"),
+            ("-x", "use -q"),
+        ];
+        #[rustfmt::skip]
+        #[cfg(feature = "long_only")]
+        let deprecated_ary = [
+            ("-c", "use -color"),
+            ("-colour", "use -color"),
+            ("-silent", ""),
+            ("-x", "use -q"),
+        ];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).deprecated(&deprecated_ary)
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+
+        assert_eq!(tokens.namevals.len(), 6);
+        #[cfg(not(feature = "long_only"))]
+        let hint = "use --color";
+        #[cfg(feature = "long_only")]
+        let hint = "use -color";
+        assert_eq!(
+            tokens.warnings,
+            vec![
+                OptParseWarning::deprecated_option("colour", hint),
+                OptParseWarning::deprecated_option("x", "use -q"),
+                OptParseWarning::deprecated_option("silent", ""),
+                OptParseWarning::deprecated_option("c", hint),
+            ]
+        );
+        assert_eq!(
+            tokens.warnings[0].kind(),
+            OptParseWarningKind::DeprecatedOption
+        );
+        assert_eq!(tokens.warnings[0].desc1_str(), "colour");
+        let thing: Vec<String> = tokens.warnings.iter().map(|w| w.to_string()).collect();
+        #[cfg(not(feature = "long_only"))]
+        assert_eq!(
+            thing,
+            vec![
+                "Deprecated option: colour: use --color",
+                "Deprecated option: x: use -q",
+                "Deprecated option: silent",
+                "Deprecated option: c: use --color",
+            ]
+        );
+        #[cfg(feature = "long_only")]
+        assert_eq!(
+            thing,
+            vec![
+                "Deprecated option: colour: use -color",
+                "Deprecated option: x: use -q",
+                "Deprecated option: silent",
+                "Deprecated option: c: use -color",
+            ]
+        );
+    }
+
+    #[test]
+    fn tokens_warnings_none() {
+        #[cfg(not(feature = "long_only"))]
+        let args = vec!["--color", "-q"];
+        #[cfg(feature = "long_only")]
+        let args = vec!["-color", "-q"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Quiet,
+            Silent,
+            X,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'x', lon: "",       has: Arg::No, num: CmdOP::X.to(), },
+            Opt { sho: b'c', lon: "color",  has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colour", has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: b'q', lon: "quiet",  has: Arg::No, num: CmdOP::Quiet.to(), },
+            Opt { sho: 0u8,  lon: "silent", has: Arg::No, num: CmdOP::Silent.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'x', lon: "",       num: CmdOP::X.to(), },
+            Opt { sho: b'c', lon: "color",  num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colour", num: CmdOP::Color.to(), },
+            Opt { sho: b'q', lon: "quiet",  num: CmdOP::Quiet.to(), },
+            Opt { sho: 0u8,  lon: "silent", num: CmdOP::Silent.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'c',1),(b'q',3),(b'x',0)];
+
+        #[rustfmt::skip]
+        #[cfg(not(feature = "long_only"))]
+        let deprecated_ary = [
+            ("--colour", "use --color"),
+            ("--silent", ""),
+            ("-c", "use --color"),
+            ("-x", "use -q"),
+        ];
+        #[rustfmt::skip]
+        #[cfg(feature = "long_only")]
+        let deprecated_ary = [
+            ("-c", "use -color"),
+            ("-colour", "use -color"),
+            ("-silent", ""),
+            ("-x", "use -q"),
+        ];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).deprecated(&deprecated_ary)
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert!(tokens.warnings.is_empty());
+        //
+        let lex = Lex::create_with(&opt_ary, &opt_ary_sho_idx);
+        #[cfg(not(feature = "long_only"))]
+        let args = vec!["--colour"];
+        #[cfg(feature = "long_only")]
+        let args = vec!["-colour"];
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert!(tokens.warnings.is_empty());
+    }
+
+    #[test]
+    fn tokens_warnings_overridden() {
+        #[cfg(not(feature = "long_only"))]
+        let args = vec!["--color", "-q", "-c", "--colour", "-q"];
+        #[cfg(feature = "long_only")]
+        let args = vec!["-color", "-q", "-c", "-colour", "-q"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Quiet,
+            Silent,
+            X,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'x', lon: "",       has: Arg::No, num: CmdOP::X.to(), },
+            Opt { sho: b'c', lon: "color",  has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colour", has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: b'q', lon: "quiet",  has: Arg::No, num: CmdOP::Quiet.to(), },
+            Opt { sho: 0u8,  lon: "silent", has: Arg::No, num: CmdOP::Silent.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'x', lon: "",       num: CmdOP::X.to(), },
+            Opt { sho: b'c', lon: "color",  num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colour", num: CmdOP::Color.to(), },
+            Opt { sho: b'q', lon: "quiet",  num: CmdOP::Quiet.to(), },
+            Opt { sho: 0u8,  lon: "silent", num: CmdOP::Silent.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'c',1),(b'q',3),(b'x',0)];
+
+        let single_values = [CmdOP::Color.to()];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).single_value(&single_values)
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(
+            tokens.warnings,
+            vec![
+                OptParseWarning::overridden_option("color", ""),
+                OptParseWarning::overridden_option("colour", ""),
+            ]
+        );
+        assert_eq!(
+            tokens.warnings[0].kind(),
+            OptParseWarningKind::OverriddenOption
+        );
+        assert_eq!(tokens.warnings[1].to_string(), "Overridden option: colour");
+    }
+
+    #[cfg(feature = "option_argument")]
+    #[test]
+    fn tokens_warnings_overridden_value() {
+        #[cfg(not(feature = "long_only"))]
+        let args = vec!["--output=a", "-o", "b"];
+        #[cfg(feature = "long_only")]
+        let args = vec!["-output=a", "-o", "b"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Output = 1,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'o', lon: "output", has: Arg::Yes, num: CmdOP::Output.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'o',0)];
+
+        let single_values = [CmdOP::Output.to()];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).single_value(&single_values)
+        };
+        let tokens = lex.tokens_from(&args).unwrap();
+        let thing: Vec<String> = tokens.warnings.iter().map(|w| w.to_string()).collect();
+        assert_eq!(thing, vec!["Overridden option: output: a"]);
+    }
+
+    #[cfg(feature = "abbreviate")]
+    #[test]
+    fn tokens_warnings_abbreviated() {
+        #[cfg(not(feature = "long_only"))]
+        let args = vec!["--qui", "--quiet", "--sil", "-q"];
+        #[cfg(feature = "long_only")]
+        let args = vec!["-qui", "-quiet", "-sil", "-q"];
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Quiet,
+            Silent,
+            X,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'x', lon: "",       has: Arg::No, num: CmdOP::X.to(), },
+            Opt { sho: b'c', lon: "color",  has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colour", has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: b'q', lon: "quiet",  has: Arg::No, num: CmdOP::Quiet.to(), },
+            Opt { sho: 0u8,  lon: "silent", has: Arg::No, num: CmdOP::Silent.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'x', lon: "",       num: CmdOP::X.to(), },
+            Opt { sho: b'c', lon: "color",  num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colour", num: CmdOP::Color.to(), },
+            Opt { sho: b'q', lon: "quiet",  num: CmdOP::Quiet.to(), },
+            Opt { sho: 0u8,  lon: "silent", num: CmdOP::Silent.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'c',1),(b'q',3),(b'x',0)];
+
+        #[rustfmt::skip]
+        #[cfg(not(feature = "long_only"))]
+        let deprecated_ary = [
+            ("--colour", "use --color"),
+            ("--silent", ""),
+            ("-c", "use --color"),
+            ("-x", "use -q"),
+        ];
+        #[rustfmt::skip]
+        #[cfg(feature = "long_only")]
+        let deprecated_ary = [
+            ("-c", "use -color"),
+            ("-colour", "use -color"),
+            ("-silent", ""),
+            ("-x", "use -q"),
+        ];
+
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).deprecated(&deprecated_ary)
+        };
+        let abbr_lex = Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+            .deprecated(&deprecated_ary)
+            .abbreviation_warning();
+        let tokens = abbr_lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.namevals.len(), 4);
+        let thing: Vec<String> = tokens.warnings.iter().map(|w| w.to_string()).collect();
+        #[cfg(not(feature = "long_only"))]
+        assert_eq!(
+            thing,
+            vec![
+                "Abbreviated option: qui: use --quiet",
+                "Abbreviated option: sil: use --silent",
+                "Deprecated option: silent",
+            ]
+        );
+        #[cfg(feature = "long_only")]
+        assert_eq!(
+            thing,
+            vec![
+                "Abbreviated option: qui: use -quiet",
+                "Abbreviated option: sil: use -silent",
+                "Deprecated option: silent",
+            ]
+        );
+        //
+        // without the setup
+        let tokens = lex.tokens_from(&args).unwrap();
+        assert_eq!(tokens.warnings.len(), 1);
+    }
+
+    #[test]
+    fn warning_other() {
+        let w = OptParseWarning::other("count is clamped", "100");
+        assert_eq!(w.kind(), OptParseWarningKind::Other);
+        assert_eq!(w.to_string(), "count is clamped: 100");
+        let w = OptParseWarning::other("count is clamped", "");
+        assert_eq!(w.to_string(), "count is clamped");
+    }
+
+    #[cfg(feature = "stop_at_mm")]
+    #[test]
+    fn parse_simple_gnu_style_with_warnings() {
+        use flood_tide::{HelpVersion, NameVal, OptParseError};
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Quiet,
+            Silent,
+            X,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'x', lon: "",       has: Arg::No, num: CmdOP::X.to(), },
+            Opt { sho: b'c', lon: "color",  has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colour", has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: b'q', lon: "quiet",  has: Arg::No, num: CmdOP::Quiet.to(), },
+            Opt { sho: 0u8,  lon: "silent", has: Arg::No, num: CmdOP::Silent.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'x', lon: "",       num: CmdOP::X.to(), },
+            Opt { sho: b'c', lon: "color",  num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colour", num: CmdOP::Color.to(), },
+            Opt { sho: b'q', lon: "quiet",  num: CmdOP::Quiet.to(), },
+            Opt { sho: 0u8,  lon: "silent", num: CmdOP::Silent.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'c',1),(b'q',3),(b'x',0)];
+
+        #[derive(Default)]
+        struct Conf {
+            quiet: bool,
+        }
+        impl HelpVersion for Conf {
+            fn is_help(&self) -> bool {
+                false
+            }
+            fn is_version(&self) -> bool {
+                false
+            }
+        }
+        fn parse_match(
+            conf: &mut Conf,
+            nv: &NameVal<'_>,
+            warnings: &mut Vec<OptParseWarning>,
+        ) -> Result<(), OptParseError> {
+            if nv.opt.num == CmdOP::Quiet.to() {
+                if conf.quiet {
+                    warnings.push(OptParseWarning::other("quiet is given twice", ""));
+                }
+                conf.quiet = true;
+            }
+            Ok(())
+        }
+
+        let args = vec!["-q", "-q", "file"];
+        let mut conf = Conf::default();
+        let (free, res, warnings) = flood_tide::parse_simple_gnu_style_with_warnings(
+            &mut conf,
+            &opt_ary,
+            &opt_ary_sho_idx,
+            &args,
+            parse_match,
+        );
+        assert!(res.is_ok());
+        assert!(conf.quiet);
+        assert_eq!(free, Some(vec!["file".to_string()]));
+        assert_eq!(
+            warnings,
+            vec![OptParseWarning::other("quiet is given twice", "")]
+        );
+    }
+
+    #[cfg(feature = "stop_at_mm")]
+    #[cfg(not(feature = "long_only"))]
+    #[test]
+    fn parse_simple_gnu_style_with_warnings_setup_lex() {
+        use flood_tide::{HelpVersion, NameVal, OptParseError};
+
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Quiet,
+        }
+
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'c', lon: "color",  has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colour", has: Arg::No, num: CmdOP::Color.to(), },
+            Opt { sho: b'q', lon: "quiet",  has: Arg::No, num: CmdOP::Quiet.to(), },
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'c', lon: "color",  num: CmdOP::Color.to(), },
+            Opt { sho: 0u8,  lon: "colour", num: CmdOP::Color.to(), },
+            Opt { sho: b'q', lon: "quiet",  num: CmdOP::Quiet.to(), },
+        ];
+
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'c',0),(b'q',2)];
+
+        #[derive(Default)]
+        struct Conf {
+            color: bool,
+        }
+        impl HelpVersion for Conf {
+            fn is_help(&self) -> bool {
+                false
+            }
+            fn is_version(&self) -> bool {
+                false
+            }
+            fn setup_lex<'a>(&self, lex: Lex<'a>) -> Lex<'a> {
+                const SINGLE_VALUES: [OptNum; 1] = [CmdOP::Quiet.to()];
+                lex.deprecated(&[("--colour", "use --color")])
+                    .single_value(&SINGLE_VALUES)
+            }
+        }
+        fn parse_match(
+            conf: &mut Conf,
+            nv: &NameVal<'_>,
+            warnings: &mut Vec<OptParseWarning>,
+        ) -> Result<(), OptParseError> {
+            if nv.opt.num == CmdOP::Color.to() {
+                if conf.color {
+                    warnings.push(OptParseWarning::other("color is given twice", ""));
+                }
+                conf.color = true;
+            }
+            Ok(())
+        }
+
+        let args = vec!["-q", "--colour", "-q", "-c", "file"];
+        let mut conf = Conf::default();
+        let (free, res, warnings) = flood_tide::parse_simple_gnu_style_with_warnings(
+            &mut conf,
+            &opt_ary,
+            &opt_ary_sho_idx,
+            &args,
+            parse_match,
+        );
+        assert!(res.is_ok());
+        assert!(conf.color);
+        assert_eq!(free, Some(vec!["file".to_string()]));
+        let thing: Vec<String> = warnings.iter().map(|w| w.to_string()).collect();
+        assert_eq!(
+            thing,
+            vec![
+                "Deprecated option: colour: use --color",
+                "Overridden option: quiet",
+                "color is given twice",
+            ]
+        );
+        //
+        // the warnings of the lex are returned with the errors of the lex
+        #[cfg(not(feature = "single_error"))]
+        {
+            let args = vec!["--colour", "--bogus"];
+            let mut conf = Conf::default();
+            let (free, res, warnings) = flood_tide::parse_simple_gnu_style_with_warnings(
+                &mut conf,
+                &opt_ary,
+                &opt_ary_sho_idx,
+                &args,
+                parse_match,
+            );
+            assert_eq!(free, None);
+            assert_eq!(format!("{}", res.unwrap_err()), "Invalid option: bogus");
+            assert_eq!(
+                warnings,
+                vec![OptParseWarning::deprecated_option("colour", "use --color")]
+            );
+        }
+    }
+}
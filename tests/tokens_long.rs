@@ -355,6 +355,78 @@ mod plain {
         };
     }
     #[test]
+    fn tokens_long_name_abbreviate_in_range() {
+        #[rustfmt::skip]
+        let args = vec!["--bet"];
+        //
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Alpha = 1,
+            Beta,
+            Betamax,
+            Gamma,
+            Zeta,
+        }
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+        //
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'\0', lon: "alpha",   has: Arg::No,  num: CmdOP::Alpha.to(), },
+            Opt { sho: b'\0', lon: "beta",    has: Arg::No,  num: CmdOP::Beta.to(), },
+            Opt { sho: b'\0', lon: "betamax", has: Arg::No,  num: CmdOP::Betamax.to(), },
+            Opt { sho: b'\0', lon: "gamma",   has: Arg::Yes, num: CmdOP::Gamma.to(), },
+            Opt { sho: b'\0', lon: "zeta",    has: Arg::No,  num: CmdOP::Zeta.to(), },
+        ];
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [];
+        //
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        #[cfg(feature = "abbreviate")]
+        match lex.tokens_from(&args) {
+            Ok(_) => unreachable!(),
+            Err(err) => {
+                let thing = format!("{err}");
+                let expect = "Ambiguous option: bet: possibilities: \'--beta\' \'--betamax\'";
+                assert_eq!(thing, expect);
+            }
+        };
+        #[cfg(feature = "abbreviate")]
+        {
+            let args = vec!["--gam", "val3", "--betam", "--a"];
+            let tokens = match lex.tokens_from(&args) {
+                Ok(t) => t,
+                Err(err) => {
+                    assert_eq!(format!("{err}"), "");
+                    unreachable!();
+                }
+            };
+            assert_eq_tokens_namevals!(tokens, 0, 0u8, "gamma", Some("val3"), CmdOP::Gamma);
+            assert_eq_tokens_namevals!(tokens, 1, 0u8, "betamax", None, CmdOP::Betamax);
+            assert_eq_tokens_namevals!(tokens, 2, 0u8, "alpha", None, CmdOP::Alpha);
+        }
+        //
+        #[cfg(not(feature = "abbreviate"))]
+        match lex.tokens_from(&args) {
+            Ok(_) => unreachable!(),
+            Err(err) => {
+                let thing = format!("{err}");
+                let expect = "Invalid option: bet";
+                assert_eq!(thing, expect);
+            }
+        };
+    }
+    #[test]
     fn tokens_long_name_ambiguous() {
         #[rustfmt::skip]
         let args = vec!["--lon", "val2"];
@@ -406,6 +478,72 @@ mod plain {
         };
     }
     #[test]
+    fn tokens_long_name_abbreviate_aliases() {
+        #[rustfmt::skip]
+        let args = vec!["--col", "auto"];
+        //
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Color = 1,
+            Columns,
+        }
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+        //
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'\0', lon: "color",   has: Arg::Yes, num: CmdOP::Color.to(), },
+            Opt { sho: b'\0', lon: "colour",  has: Arg::Yes, num: CmdOP::Color.to(), },
+            Opt { sho: b'\0', lon: "columns", has: Arg::Yes, num: CmdOP::Columns.to(), },
+        ];
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [];
+        //
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        // the aliases of the same num are not ambiguous
+        #[cfg(feature = "abbreviate")]
+        {
+            let args = vec!["--colo", "auto", "--colou", "never"];
+            let tokens = match lex.tokens_from(&args) {
+                Ok(t) => t,
+                Err(err) => {
+                    assert_eq!(format!("{err}"), "");
+                    unreachable!();
+                }
+            };
+            assert_eq_tokens_namevals!(tokens, 0, 0u8, "color", Some("auto"), CmdOP::Color);
+            assert_eq_tokens_namevals!(tokens, 1, 0u8, "colour", Some("never"), CmdOP::Color);
+        }
+        #[cfg(feature = "abbreviate")]
+        match lex.tokens_from(&args) {
+            Ok(_) => unreachable!(),
+            Err(err) => {
+                let thing = format!("{err}");
+                let expect = "Ambiguous option: col: possibilities: \'--color\' \'--columns\'";
+                assert_eq!(thing, expect);
+            }
+        };
+        #[cfg(not(feature = "abbreviate"))]
+        match lex.tokens_from(&args) {
+            Ok(_) => unreachable!(),
+            Err(err) => {
+                let thing = format!("{err}");
+                let expect = "Invalid option: col";
+                assert_eq!(thing, expect);
+            }
+        };
+    }
+    #[test]
     fn tokens_long_name_ok_nearly_ambiguous() {
         #[rustfmt::skip]
         let args = vec!["--lon", "val2"];
@@ -59,6 +59,61 @@ mod plain {
         #[cfg(feature = "stop_at_mm")]
         assert!(!tokens.double_m);
     }
+    #[test]
+    fn tokens_free_stdin() {
+        #[rustfmt::skip]
+        let args = vec!["-a", "-", "other1", "-"];
+        //
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            A = 1,
+        }
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+        //
+        #[rustfmt::skip]
+        #[cfg(feature = "option_argument")]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "", has: Arg::No, num: CmdOP::A.to(), }
+        ];
+        #[rustfmt::skip]
+        #[cfg(not(feature = "option_argument"))]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "", num: CmdOP::A.to(), }
+        ];
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0)];
+        //
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx)
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+        //
+        assert_eq!(tokens.namevals.len(), 1);
+        assert_eq_tokens_namevals!(tokens, 0, b'a', "", None, CmdOP::A);
+        //
+        assert_eq!(tokens.free.len(), 3);
+        assert_eq_tokens_free!(tokens, 0, "-");
+        assert_eq_tokens_free!(tokens, 1, "other1");
+        assert_eq_tokens_free!(tokens, 2, "-");
+        //
+        #[cfg(feature = "stop_at_mm")]
+        assert!(!tokens.double_m);
+    }
     //}}} free
     //
     //{{{ stop at
@@ -393,4 +393,68 @@ mod subcommand {
             }
         };
     }
+    #[test]
+    fn tokens_subcommand_exact_match() {
+        #[cfg(not(feature = "long_only"))]
+        #[rustfmt::skip]
+        let args = vec!["-a", "val1", "--long2=val2", "-c", "cmd"];
+        #[cfg(feature = "long_only")]
+        #[rustfmt::skip]
+        let args = vec!["-a", "val1", "-long2=val2", "-c", "cmd"];
+        //
+        #[repr(u8)]
+        #[derive(Debug, PartialEq)]
+        enum CmdOP {
+            Long1 = 1,
+            Long2 = 2,
+            Long3 = 3,
+        }
+        impl CmdOP {
+            pub const fn to(self) -> OptNum {
+                self as OptNum
+            }
+        }
+        //
+        #[rustfmt::skip]
+        let opt_ary = [
+            Opt { sho: b'a', lon: "long1", has: Arg::Yes, num: CmdOP::Long1.to(), },
+            Opt { sho: 0u8,  lon: "long2", has: Arg::Yes, num: CmdOP::Long2.to(), },
+            Opt { sho: b'c', lon: "long3", has: Arg::No,  num: CmdOP::Long3.to(), },
+        ];
+        #[rustfmt::skip]
+        let opt_ary_sho_idx = [(b'a',0),(b'c',2)];
+        //
+        let lex = {
+            assert!(check::check_sorted_opt_ary_and_sho_idx_ary_with(
+                &opt_ary,
+                &opt_ary_sho_idx
+            ));
+            Lex::create_with(&opt_ary, &opt_ary_sho_idx).subcmd(&["cmd", "cmd1", "cmd2"])
+        };
+        let tokens = match lex.tokens_from(&args) {
+            Ok(t) => t,
+            Err(e) => {
+                assert_eq!(format!("{}", e), "");
+                unreachable!();
+            }
+        };
+        //
+        assert_eq_tokens_namevals!(tokens, 2, b'c', "long3", None, CmdOP::Long3);
+        assert_eq_tokens_subcmd!(tokens, "cmd");
+    }
+    #[test]
+    fn tokens_subcommand_not_sorted() {
+        let lex = Lex::create_with(&[], &[]).subcmd(&["cmd2", "cmd", "cmd1"]);
+        for (arg, expect) in [("cmd1", "cmd1"), ("cmd", "cmd"), ("cmd2", "cmd2")] {
+            let args = vec![arg];
+            let tokens = match lex.tokens_from(&args) {
+                Ok(t) => t,
+                Err(e) => {
+                    assert_eq!(format!("{}", e), "");
+                    unreachable!();
+                }
+            };
+            assert_eq_tokens_subcmd!(tokens, expect);
+        }
+    }
 } // mod subcommand
@@ -18,7 +18,6 @@ fn criterion_test(_c: &mut Criterion) {
         opt_config: None,
         arg_input: "inp".to_string(),
         arg_output: Some("oup".to_string()),
-        ..Default::default()
     };
     match process_one(criterion::black_box(&ENV_ARGS)) {
         Ok(conf) => {
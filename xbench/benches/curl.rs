@@ -1,5 +1,4 @@
 use flood_tide::OptParseError;
-#[cfg(not(feature = "single_error"))]
 use flood_tide::OptParseErrors;
 
 use flood_tide::Arg;
@@ -67,7 +66,7 @@ fn print_version_and_exit(conf: &CmdOptConf) {
 fn value_to_string(nv: &NameVal<'_>) -> Result<String, OptParseError> {
     match nv.val {
         Some(x) => Ok(x.to_string()),
-        None => Err(OptParseError::missing_option_argument(&nv.opt.lon)),
+        None => Err(OptParseError::missing_option_argument(nv.opt.lon)),
     }
 }
 
@@ -77,11 +76,11 @@ fn value_to_u32(nv: &NameVal<'_>) -> Result<u32, OptParseError> {
         Some(x) => match x.parse::<u32>() {
             Ok(d) => Ok(d),
             Err(err) => Err(OptParseError::invalid_option_argument(
-                &nv.opt.lon,
+                nv.opt.lon,
                 &err.to_string(),
             )),
         },
-        None => Err(OptParseError::missing_option_argument(&nv.opt.lon)),
+        None => Err(OptParseError::missing_option_argument(nv.opt.lon)),
     }
 }
 
@@ -91,11 +90,11 @@ fn value_to_u64(nv: &NameVal<'_>) -> Result<u64, OptParseError> {
         Some(x) => match x.parse::<u64>() {
             Ok(d) => Ok(d),
             Err(err) => Err(OptParseError::invalid_option_argument(
-                &nv.opt.lon,
+                nv.opt.lon,
                 &err.to_string(),
             )),
         },
-        None => Err(OptParseError::missing_option_argument(&nv.opt.lon)),
+        None => Err(OptParseError::missing_option_argument(nv.opt.lon)),
     }
 }
 
@@ -118,42 +117,26 @@ pub fn parse_cmdopts(program: &str, args: &[&str]) -> Result<CmdOptConf, OpErr>
     let (opt_free, r_errs) =
         parse_simple_gnu_style(&mut conf, &OPT_ARY, &OPT_ARY_SHO_IDX, args, parse_match);
     //
-    #[cfg(feature = "single_error")]
-    {
-        if let Err(err) = r_errs {
-            return Err(From::from(err));
-        }
-        if let Some(free) = opt_free {
-            if !free.is_empty() {
-                conf.arg_params = free;
-            } else {
-                return Err(From::from(OptParseError::missing_argument("<url>")));
-            }
-        }
-    }
-    #[cfg(not(feature = "single_error"))]
-    {
-        let mut errs = if let Err(errs) = r_errs {
-            errs
-        } else {
-            OptParseErrors::new()
-        };
-        let missing_arg = if let Some(free) = opt_free {
-            if !free.is_empty() {
-                conf.arg_params = free;
-                false
-            } else {
-                true
-            }
+    let mut errs = if let Err(errs) = r_errs {
+        errs
+    } else {
+        OptParseErrors::new()
+    };
+    let missing_arg = if let Some(free) = opt_free {
+        if !free.is_empty() {
+            conf.arg_params = free;
+            false
         } else {
             true
-        };
-        if missing_arg {
-            errs.push(OptParseError::missing_argument("<url>"));
-        }
-        if !errs.is_empty() {
-            return Err(From::from(errs));
         }
+    } else {
+        true
+    };
+    if missing_arg {
+        errs.push(OptParseError::missing_argument("<url>"));
+    }
+    if !errs.is_empty() {
+        return Err(errs);
     }
     //
     Ok(conf)
@@ -1,5 +1,4 @@
 use flood_tide::OptParseError;
-#[cfg(not(feature = "single_error"))]
 use flood_tide::OptParseErrors;
 
 use flood_tide::Arg;
@@ -100,13 +99,6 @@ fn print_version_and_exit(_conf: &CmdOptConf) {
 
 #[inline(never)]
 fn mk_invalid_option_argument(nv: &NameVal, err_s: &str) -> OptParseError {
-    #[cfg(feature = "was_long")]
-    let name = if nv.was_long {
-        nv.opt.lon.to_string()
-    } else {
-        (nv.opt.sho as char).to_string()
-    };
-    #[cfg(not(feature = "was_long"))]
     let name = nv.opt.lon.to_string();
     OptParseError::invalid_option_argument(&name, err_s)
 }
@@ -118,7 +110,7 @@ fn value_to_f32(nv: &NameVal<'_>) -> Result<f32, OptParseError> {
             Ok(d) => Ok(d),
             Err(err) => Err(mk_invalid_option_argument(nv, &err.to_string())),
         },
-        None => Err(OptParseError::missing_option_argument(&nv.opt.lon)),
+        None => Err(OptParseError::missing_option_argument(nv.opt.lon)),
     }
 }
 
@@ -141,10 +133,7 @@ fn parse_match(conf: &mut CmdOptConf, nv: &NameVal<'_>) -> Result<(), OptParseEr
             conf.opt_speed = value_to_f32(nv)?;
         }
         CmdOP::Config => {
-            conf.opt_config = match nv.val {
-                Some(s) => Some(s.to_string()),
-                None => None,
-            };
+            conf.opt_config = nv.val.map(|s| s.to_string());
         }
     }
     Ok(())
@@ -154,52 +143,6 @@ pub fn check_sorted_opt_ary_and_sho_idx_ary() -> bool {
     check_sorted_opt_ary_and_sho_idx_ary_with(&OPT_ARY, &OPT_ARY_SHO_IDX)
 }
 
-#[cfg(feature = "single_error")]
-#[inline(never)]
-pub fn parse_cmdopts(program: &str, env_args: &[&str]) -> Result<CmdOptConf, OptParseError> {
-    //
-    let mut conf = CmdOptConf {
-        opt_program: program.to_string(),
-        opt_speed: 42.0,
-        ..Default::default()
-    };
-    //
-    let lex = Lex::create_with(&OPT_ARY, &OPT_ARY_SHO_IDX);
-    let tokens = match lex.tokens_from(&env_args) {
-        Ok(t) => t,
-        Err(err) => return Err(err),
-    };
-    //
-    for nv in tokens.namevals.iter() {
-        match parse_match(&mut conf, &nv) {
-            Ok(_) => {}
-            Err(err) => return Err(err),
-        }
-    }
-    let free = tokens.free;
-    if !free.is_empty() {
-        conf.arg_input = free[0].to_string();
-        conf.arg_output = if free.len() > 1 {
-            Some(free[1].to_string())
-        } else {
-            None
-        };
-    } else {
-        return Err(OptParseError::missing_argument("<input>"));
-    }
-    //
-    Ok(conf)
-}
-/*
-#[cfg(feature = "single_error")]
-pub fn create_conf() -> Result<CmdOptConf, OptParseError> {
-    let mut env_args: Vec<String> = env::args().collect();
-    let program = env_args.remove(0);
-    let env_args: Vec<&str> = env_args.iter().map(std::string::String::as_str).collect();
-    parse_cmdopts(&program, env_args)
-}
-*/
-#[cfg(not(feature = "single_error"))]
 //#[inline(never)]
 pub fn parse_cmdopts(program: &str, env_args: &[&str]) -> Result<CmdOptConf, OptParseErrors> {
     //
@@ -210,7 +153,7 @@ pub fn parse_cmdopts(program: &str, env_args: &[&str]) -> Result<CmdOptConf, Opt
     };
     //
     let lex = Lex::create_with(&OPT_ARY, &OPT_ARY_SHO_IDX);
-    let tokens = match lex.tokens_from(&env_args) {
+    let tokens = match lex.tokens_from(env_args) {
         Ok(t) => t,
         Err(errs) => {
             return Err(errs);
@@ -219,7 +162,7 @@ pub fn parse_cmdopts(program: &str, env_args: &[&str]) -> Result<CmdOptConf, Opt
     //
     let mut errs = OptParseErrors::new();
     for nv in tokens.namevals.iter() {
-        match parse_match(&mut conf, &nv) {
+        match parse_match(&mut conf, nv) {
             Ok(_) => {}
             Err(e) => errs.push(e),
         }
@@ -242,7 +185,6 @@ pub fn parse_cmdopts(program: &str, env_args: &[&str]) -> Result<CmdOptConf, Opt
     Ok(conf)
 }
 /*
-#[cfg(not(feature = "single_error"))]
 pub fn create_conf() -> Result<CmdOptConf, OptParseErrors> {
     let mut env_args: Vec<String> = env::args().collect();
     let program = env_args.remove(0);
@@ -0,0 +1,351 @@
+//! Generating the source code from the spec.
+
+use crate::{GenConf, OptStr, Output, Section, Spec};
+use std::cmp::Ordering;
+
+/// Generate the tables and the conf struct.
+///
+/// This includes `OPTIONS_TEXT`, the option enum, `OPT_ARY`,
+/// `OPT_ARY_SHO_IDX`, the conf struct and `HelpVersion`.
+/// The aliases are only in `OPT_ARY` and `OPT_ARY_SHO_IDX`.
+/// With `@expand`, this also includes `EXPAND_ARY` for `Lex::expand()`.
+pub fn gen_src_help(sect: &Section, conf: &GenConf) -> anyhow::Result<String> {
+    let enum_name = &conf.naming.enum_name;
+    let conf_name = &conf.naming.conf_name;
+    let mut sss = String::with_capacity(4 * 1024);
+    //
+    let s = r"// WARN: This file is auto generated by";
+    sss += &format!("{} {}", s, conf.generator);
+    let hash = if sect.lines.iter().any(|line| line.contains('"')) {
+        "#"
+    } else {
+        ""
+    };
+    sss += &format!("\nconst OPTIONS_TEXT: &str = r{hash}\"");
+    for line in &sect.lines {
+        sss += &format!("{line}\n");
+    }
+    sss += &format!("\"{hash};\n");
+    //
+    sss += &format!(
+        r#"
+#[repr(u8)]
+#[derive(Debug, PartialEq)]
+enum {enum_name} {{
+"#
+    );
+    for rec in sect.opts.iter().filter(|o| !o.alias) {
+        sss += &format!("    {},\n", rec.enum_s);
+    }
+    sss += "}\n";
+    sss += &format!(
+        r#"
+impl std::convert::From<OptNum> for {enum_name} {{
+    fn from(value: OptNum) -> Self {{
+        unsafe {{ std::mem::transmute_copy(&value) }}
+    }}
+}}
+impl {enum_name} {{
+    pub const fn to(self) -> OptNum {{
+        self as OptNum
+    }}
+}}
+"#
+    );
+    //
+    let vec_optstr_sorted = sorted_opts(&sect.opts);
+    let s = r#"
+#[rustfmt::skip]
+const OPT_ARY: [Opt;"#;
+    sss += &format!("{}{}] = [\n", s, vec_optstr_sorted.len());
+    for rec in vec_optstr_sorted.iter() {
+        sss += "    Opt { ";
+        if rec.sho.is_empty() {
+            sss += "sho: 0u8,  ";
+        } else {
+            sss += &format!("sho: b'{}', ", rec.sho);
+        }
+        let s = "\"".to_string() + &rec.lon + "\",";
+        sss += &format!("lon: {s:-17}");
+        sss += if rec.meta.is_empty() {
+            "has: Arg::No,  "
+        } else {
+            "has: Arg::Yes, "
+        };
+        sss += &format!("num: {}::{}.to(), ", enum_name, rec.enum_s);
+        sss += "},\n";
+    }
+    sss += "];\n";
+    //
+    let mut vec_optstr_sho_idx: Vec<(_, usize)> = vec_optstr_sorted
+        .iter()
+        .enumerate()
+        .filter(|(_, &o)| !o.sho.is_empty())
+        .map(|(i, &o)| (&o.sho, i))
+        .collect();
+    vec_optstr_sho_idx.sort_by(|a, b| a.0.cmp(b.0));
+    //
+    let s = r#"
+#[rustfmt::skip]
+const OPT_ARY_SHO_IDX: [(u8,usize);"#;
+    sss += &format!("{}{}] = [\n", s, vec_optstr_sho_idx.len());
+    for elm in vec_optstr_sho_idx.iter() {
+        sss += &format!("(b'{}',{}),", elm.0, elm.1);
+    }
+    sss += "];\n";
+    //
+    let vec_optstr_expand: Vec<&OptStr> =
+        sect.opts.iter().filter(|o| !o.expand.is_empty()).collect();
+    if !vec_optstr_expand.is_empty() {
+        let s = r#"
+#[rustfmt::skip]
+const EXPAND_ARY: [Expand;"#;
+        sss += &format!("{}{}] = [\n", s, vec_optstr_expand.len());
+        for rec in vec_optstr_expand.iter() {
+            sss += &format!("    ({}::{}.to(), &[", enum_name, rec.enum_s);
+            for (name, val) in rec.expand.iter() {
+                let to = match sect.find_opt(name) {
+                    Some(to) => to,
+                    None => anyhow::bail!("unknown expand of {}: {}", rec.lon_or_sho(), name),
+                };
+                let val = match val {
+                    Some(val) => format!("Some({:?})", val),
+                    None => "None".to_string(),
+                };
+                sss += &format!("({}::{}.to(), {}),", enum_name, to.enum_s, val);
+            }
+            sss += "]),\n";
+        }
+        sss += "];\n";
+    }
+    //
+    sss += &format!(
+        r#"
+#[derive(Debug, Default, PartialEq)]
+pub struct {conf_name} {{
+    pub opt_program: String,
+    //
+"#
+    );
+    for rec in sect.opts.iter().filter(|o| !o.alias) {
+        sss += &format!("    pub {}: {},\n", rec.field_s, rec.type_s);
+    }
+    sss += r#"    //
+    pub arg_params: Vec<String>,
+}
+"#;
+    let is_help = match find_bool_opt(&sect.opts, "help") {
+        Some(rec) => format!("self.{}", rec.field_s),
+        None => "false".to_string(),
+    };
+    let is_version = match find_bool_opt(&sect.opts, "version") {
+        Some(rec) => format!("self.{}", rec.field_s),
+        None => "false".to_string(),
+    };
+    sss += &format!(
+        r#"
+impl flood_tide::HelpVersion for {conf_name} {{
+    fn is_help(&self) -> bool {{
+        {is_help}
+    }}
+    fn is_version(&self) -> bool {{
+        {is_version}
+    }}
+}}
+"#
+    );
+    //
+    Ok(sss)
+}
+
+/// Generate the match of `parse_match()`.
+///
+/// The option argument is converted by `value_to_<type>(nv)`,
+/// that is `value_to_string()`, `value_to_u32()` and so on.
+/// `--help` and `--version` call `print_help_and_exit(conf)`
+/// and `print_version_and_exit(conf)`.
+pub fn gen_src_match(sect: &Section, conf: &GenConf) -> anyhow::Result<String> {
+    let enum_name = &conf.naming.enum_name;
+    let mut sss = String::with_capacity(4 * 1024);
+    //
+    let s = r"// WARN: This file is auto generated by";
+    sss += &format!("{} {}", s, conf.generator);
+    //
+    sss += &format!(
+        r#"
+match {enum_name}::from(nv.opt.num) {{
+"#
+    );
+    for rec in sect.opts.iter().filter(|o| !o.alias) {
+        sss += &format!("    {}::{} => {{\n", enum_name, rec.enum_s);
+        match rec.type_s.as_str() {
+            "bool" => match rec.lon.as_str() {
+                "help" => {
+                    sss += "        print_help_and_exit(conf);\n";
+                }
+                "version" => {
+                    sss += "        print_version_and_exit(conf);\n";
+                }
+                _ => {
+                    sss += &format!("        conf.{} = true;\n", rec.field_s);
+                }
+            },
+            type_s => {
+                let conv = to_snake_type(type_s);
+                sss += &format!("        conf.{} = value_to_{}(nv)?;\n", rec.field_s, conv);
+            }
+        }
+        sss += "    }\n";
+    }
+    sss += r#"}
+"#;
+    //
+    Ok(sss)
+}
+
+/// Generate the `argparse!` invocation.
+///
+/// The field types are taken from [`GenConf::types`], or inferred from
+/// the meta by [`OptStr::infer_type()`]. `--help` and `--version` are
+/// marked with `@help` and `@version`. The alias and the expand are
+/// not supported.
+pub fn gen_src_argparse(sect: &Section, conf: &GenConf) -> anyhow::Result<String> {
+    if let Some(rec) = sect.opts.iter().find(|o| o.alias) {
+        anyhow::bail!("alias is not supported by argparse!: {}", rec.lon_or_sho());
+    }
+    if let Some(rec) = sect.opts.iter().find(|o| !o.expand.is_empty()) {
+        anyhow::bail!("expand is not supported by argparse!: {}", rec.lon_or_sho());
+    }
+    let conf_name = &conf.naming.conf_name;
+    let mut sss = String::with_capacity(4 * 1024);
+    //
+    let s = r"// WARN: This file is auto generated by";
+    sss += &format!("{} {}", s, conf.generator);
+    sss += &format!(
+        r#"
+flood_tide::argparse! {{
+    pub struct {conf_name} {{
+"#
+    );
+    for rec in sect.opts.iter() {
+        let type_s = match conf.type_of(rec.lon_or_sho()) {
+            Some(type_s) => type_s,
+            None => rec.infer_type(),
+        };
+        let sho = if rec.sho.is_empty() {
+            "0u8".to_string()
+        } else {
+            format!("b'{}'", rec.sho)
+        };
+        let has = if rec.meta.is_empty() {
+            "Arg::No"
+        } else {
+            "Arg::Yes"
+        };
+        let special = match (type_s, rec.lon.as_str()) {
+            ("bool", "help") => ", @help",
+            ("bool", "version") => ", @version",
+            _ => "",
+        };
+        sss += &format!(
+            "        ({}, {}, {}, {:?}, flood_tide::{}, {:?}{}),\n",
+            rec.field_s, type_s, sho, rec.lon, has, rec.comment, special
+        );
+    }
+    sss += r#"    }
+}
+"#;
+    //
+    Ok(sss)
+}
+
+/// Generate the subcommand array `SUBCMD_ARY`, that is sorted for `Lex::subcmd()`.
+pub fn gen_src_subcmd(spec: &Spec, conf: &GenConf) -> anyhow::Result<String> {
+    let mut subcmds = spec.subcmds();
+    subcmds.sort_unstable();
+    let mut sss = String::with_capacity(1024);
+    //
+    let s = r"// WARN: This file is auto generated by";
+    sss += &format!("{} {}", s, conf.generator);
+    sss += &format!("\nconst SUBCMD_ARY: [&str;{}] = [", subcmds.len());
+    for subcmd in subcmds {
+        sss += &format!("\"{subcmd}\",");
+    }
+    sss += "];\n";
+    //
+    Ok(sss)
+}
+
+/// Write all the generated files into `out_dir`.
+///
+/// - `<stem>.help.rs.txt`, `<stem>.match.rs.txt`: the top level options
+/// - `<stem>.<subcmd>.help.rs.txt`, `<stem>.<subcmd>.match.rs.txt`: the subcommand options
+/// - `<stem>.subcmd.rs.txt`: `SUBCMD_ARY`, if the spec has subcommands
+///
+/// With [`Output::Argparse`], `<stem>.argparse.rs.txt` and
+/// `<stem>.<subcmd>.argparse.rs.txt` are written instead of
+/// the help and the match.
+pub fn write_files(
+    spec: &Spec,
+    conf: &GenConf,
+    out_dir: &std::path::Path,
+    stem: &str,
+) -> anyhow::Result<()> {
+    let path_of = |name: &str| -> String { out_dir.join(name).to_string_lossy().to_string() };
+    for sect in &spec.sections {
+        let base = match sect.subcmd {
+            Some(ref subcmd) => format!("{stem}.{subcmd}"),
+            None => stem.to_string(),
+        };
+        match conf.output {
+            Output::Tables => {
+                let sss = gen_src_help(sect, conf)?;
+                crate::update_file(&sss, &path_of(&format!("{base}.help.rs.txt")))?;
+                let sss = gen_src_match(sect, conf)?;
+                crate::update_file(&sss, &path_of(&format!("{base}.match.rs.txt")))?;
+            }
+            Output::Argparse => {
+                let sss = gen_src_argparse(sect, conf)?;
+                crate::update_file(&sss, &path_of(&format!("{base}.argparse.rs.txt")))?;
+            }
+        }
+    }
+    if !spec.subcmds().is_empty() {
+        let sss = gen_src_subcmd(spec, conf)?;
+        crate::update_file(&sss, &path_of(&format!("{stem}.subcmd.rs.txt")))?;
+    }
+    //
+    Ok(())
+}
+
+fn sorted_opts(opts: &[OptStr]) -> Vec<&OptStr> {
+    let mut target: Vec<&OptStr> = opts.iter().collect();
+    target.sort_by(|&a, &b| match a.lon.cmp(&b.lon) {
+        Ordering::Less => Ordering::Less,
+        Ordering::Greater => Ordering::Greater,
+        Ordering::Equal => match a.sho.cmp(&b.sho) {
+            Ordering::Less => Ordering::Less,
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Equal => a.num.cmp(&b.num),
+        },
+    });
+    target
+}
+
+fn find_bool_opt<'a>(opts: &'a [OptStr], lon: &str) -> Option<&'a OptStr> {
+    opts.iter()
+        .find(|o| !o.alias && o.lon == lon && o.type_s == "bool")
+}
+
+// ex) `Option<String>` is `option_string`
+fn to_snake_type(type_s: &str) -> String {
+    let mut s = String::with_capacity(type_s.len());
+    for c in type_s.chars() {
+        if c.is_ascii_alphanumeric() {
+            s.push(c.to_ascii_lowercase());
+        } else if !s.is_empty() && !s.ends_with('_') {
+            s.push('_');
+        }
+    }
+    s.trim_end_matches('_').to_string()
+}
@@ -0,0 +1,139 @@
+/*!
+Generating *flood-tide* tables from a help text spec file.
+
+The spec file is a plain help text, like `examples/curl.cmd.txt`:
+
+```text
+Options:
+  -a, --append              Append to target file when uploading
+      --cacert <file>       CA certificate to verify peer against
+  -e                        equivalent to -vE
+
+Subcommand clone:
+  -b, --branch <name>       checkout <name>
+```
+
+Each section generates the `CmdOP` enum, `OPT_ARY`, `OPT_ARY_SHO_IDX`,
+the conf struct and a `parse_match` skeleton, or the `argparse!` invocation
+with [`Output::Argparse`]. These are suitable for `include!` from the source
+or from the output of `build.rs`.
+
+# Examples
+
+build.rs:
+```no_run
+let conf = xgen::GenConf::default();
+let spec = xgen::parse_spec_file("src/cmd.txt", &conf).unwrap();
+let out_dir = std::env::var("OUT_DIR").unwrap();
+xgen::write_files(&spec, &conf, std::path::Path::new(&out_dir), "cmd").unwrap();
+```
+
+src/main.rs:
+```text
+include!(concat!(env!("OUT_DIR"), "/cmd.help.rs.txt"));
+
+fn parse_match(conf: &mut CmdOptConf, nv: &NameVal<'_>) -> Result<(), OptParseError> {
+    include!(concat!(env!("OUT_DIR"), "/cmd.match.rs.txt"));
+    Ok(())
+}
+```
+*/
+use anyhow::Context;
+use std::io::Read;
+use std::io::Write;
+
+mod gen_src;
+mod spec;
+
+pub use gen_src::{gen_src_argparse, gen_src_help, gen_src_match, gen_src_subcmd, write_files};
+pub use spec::{parse_spec, parse_spec_file, OptStr, Section, Spec};
+
+/// Naming of the generated enum, struct and fields
+#[derive(Debug, Clone)]
+pub struct Naming {
+    /// name of the option enum. ex) `CmdOP`
+    pub enum_name: String,
+    /// name of the conf struct. ex) `CmdOptConf`
+    pub conf_name: String,
+    /// prefix of the enum variants. ex) `Opt` makes `OptAppend`
+    pub enum_prefix: String,
+    /// prefix of the flag fields. ex) `flg_` makes `flg_append`
+    pub flag_prefix: String,
+    /// prefix of the option argument fields. ex) `opt_` makes `opt_cacert`
+    pub opt_prefix: String,
+}
+
+impl Default for Naming {
+    fn default() -> Self {
+        Self {
+            enum_name: "CmdOP".to_string(),
+            conf_name: "CmdOptConf".to_string(),
+            enum_prefix: "".to_string(),
+            flag_prefix: "flg_".to_string(),
+            opt_prefix: "opt_".to_string(),
+        }
+    }
+}
+
+/// Style of the generated source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+    /// the tables and the `parse_match` skeleton
+    Tables,
+    /// the `argparse!` invocation
+    Argparse,
+}
+
+/// Configuration of generating
+#[derive(Debug, Clone)]
+pub struct GenConf {
+    /// generator name, that is written in the header comment
+    pub generator: String,
+    /// naming of the generated source
+    pub naming: Naming,
+    /// field types by long name (or short name). the default is `bool` or `String`
+    pub types: Vec<(String, String)>,
+    /// style of the generated source by `write_files()`
+    pub output: Output,
+}
+
+impl Default for GenConf {
+    fn default() -> Self {
+        Self {
+            generator: env!("CARGO_PKG_NAME").to_string(),
+            naming: Naming::default(),
+            types: Vec::new(),
+            output: Output::Tables,
+        }
+    }
+}
+
+impl GenConf {
+    /// field type of the option name
+    pub fn type_of(&self, name: &str) -> Option<&str> {
+        self.types
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, t)| t.as_str())
+    }
+}
+
+/// Write `sss` into `file_path` only if the contents is changed.
+pub fn update_file(sss: &str, file_path: &str) -> anyhow::Result<()> {
+    let contents = {
+        let mut contents = String::new();
+        if let Ok(mut file) = std::fs::File::open(file_path) {
+            file.read_to_string(&mut contents)
+                .with_context(|| format!("could not read file `{file_path}`"))?;
+        }
+        contents
+    };
+    if contents != *sss {
+        println!("update: {file_path}");
+        let mut file = std::fs::File::create(file_path)
+            .with_context(|| format!("could not create file `{file_path}`"))?;
+        write!(file, "{sss}").with_context(|| format!("could not write file `{file_path}`"))?;
+    }
+    //
+    Ok(())
+}
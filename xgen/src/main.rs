@@ -0,0 +1,245 @@
+//! xgen: generating flood-tide tables from the help text spec file.
+
+use flood_tide::parse_simple_gnu_style;
+use flood_tide::Arg;
+use flood_tide::HelpVersion;
+use flood_tide::NameVal;
+use flood_tide::OpErr;
+use flood_tide::Opt;
+use flood_tide::OptNum;
+use flood_tide::OptParseError;
+
+//----------------------------------------------------------------------
+//{{{ TEXT
+const DESCRIPTIONS_TEXT: &str = r#"
+Generate flood-tide tables from <spec-file>, that is a help text.
+"#;
+
+const OPTIONS_TEXT: &str = r#"Options:
+  -o, --out-dir <dir>       output directory (default: .)
+  -s, --stem <name>         output file stem (default: <spec-file> without .txt)
+      --generator <name>    generator name in the header comment
+      --enum-name <name>    option enum name (default: CmdOP)
+      --conf-name <name>    conf struct name (default: CmdOptConf)
+      --enum-prefix <str>   prefix of the enum variants
+      --flag-prefix <str>   prefix of the flag fields (default: flg_)
+      --opt-prefix <str>    prefix of the option fields (default: opt_)
+  -t, --type <name=type>    field type of the option, can be repeated
+  -a, --argparse            output the argparse! invocation
+  -H, --help                display this help and exit
+  -V, --version             output version information and exit
+"#;
+
+const ARGUMENTS_TEXT: &str = r#"Argument:
+  <spec-file>               help text spec file. ex) examples/curl.cmd.txt
+"#;
+//}}} TEXT
+
+#[repr(u8)]
+#[derive(Debug, PartialEq)]
+enum CmdOP {
+    Argparse = 1,
+    OutDir,
+    Stem,
+    Generator,
+    EnumName,
+    ConfName,
+    EnumPrefix,
+    FlagPrefix,
+    OptPrefix,
+    Type,
+    //
+    Help,
+    Version,
+}
+impl std::convert::From<OptNum> for CmdOP {
+    fn from(value: OptNum) -> Self {
+        unsafe { std::mem::transmute_copy(&value) }
+    }
+}
+impl CmdOP {
+    pub const fn to(self) -> OptNum {
+        self as OptNum
+    }
+}
+
+#[rustfmt::skip]
+const OPT_ARY: [Opt;12] = [
+    Opt { sho: b'a', lon: "argparse",    has: Arg::No,  num: CmdOP::Argparse.to(), },
+    Opt { sho: 0u8,  lon: "conf-name",   has: Arg::Yes, num: CmdOP::ConfName.to(), },
+    Opt { sho: 0u8,  lon: "enum-name",   has: Arg::Yes, num: CmdOP::EnumName.to(), },
+    Opt { sho: 0u8,  lon: "enum-prefix", has: Arg::Yes, num: CmdOP::EnumPrefix.to(), },
+    Opt { sho: 0u8,  lon: "flag-prefix", has: Arg::Yes, num: CmdOP::FlagPrefix.to(), },
+    Opt { sho: 0u8,  lon: "generator",   has: Arg::Yes, num: CmdOP::Generator.to(), },
+    Opt { sho: b'H', lon: "help",        has: Arg::No,  num: CmdOP::Help.to(), },
+    Opt { sho: 0u8,  lon: "opt-prefix",  has: Arg::Yes, num: CmdOP::OptPrefix.to(), },
+    Opt { sho: b'o', lon: "out-dir",     has: Arg::Yes, num: CmdOP::OutDir.to(), },
+    Opt { sho: b's', lon: "stem",        has: Arg::Yes, num: CmdOP::Stem.to(), },
+    Opt { sho: b't', lon: "type",        has: Arg::Yes, num: CmdOP::Type.to(), },
+    Opt { sho: b'V', lon: "version",     has: Arg::No,  num: CmdOP::Version.to(), },
+];
+
+#[rustfmt::skip]
+const OPT_ARY_SHO_IDX: [(u8,usize);6] = [
+    (b'H',6), (b'V',11), (b'a',0), (b'o',8), (b's',9), (b't',10),
+];
+
+//----------------------------------------------------------------------
+#[derive(Debug, Default)]
+struct CmdOptConf {
+    pub opt_program: String,
+    //
+    pub opt_out_dir: String,
+    pub opt_stem: String,
+    pub gen_conf: xgen::GenConf,
+    //
+    pub flag_help: bool,
+    pub flag_version: bool,
+    //
+    pub arg_params: Vec<String>,
+}
+impl HelpVersion for CmdOptConf {
+    fn is_help(&self) -> bool {
+        self.flag_help
+    }
+    fn is_version(&self) -> bool {
+        self.flag_version
+    }
+}
+
+//----------------------------------------------------------------------
+#[rustfmt::skip]
+fn version_message(_program: &str) -> String {
+    format!( "{} {}",
+        env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+}
+
+#[rustfmt::skip]
+fn usage_message(program: &str) -> String {
+    format!("Usage:\n  {} {}", program, "[options] <spec-file>")
+}
+
+#[rustfmt::skip]
+fn help_message(program: &str) -> String {
+    let ver = version_message(program);
+    let usa = usage_message("xgen");
+    [ &ver, "", &usa, DESCRIPTIONS_TEXT, OPTIONS_TEXT, ARGUMENTS_TEXT].join("\n")
+}
+
+fn value_to_string(nv: &NameVal<'_>) -> Result<String, OptParseError> {
+    match nv.val {
+        Some(x) => Ok(x.to_string()),
+        None => Err(OptParseError::missing_option_argument(nv.opt.lon)),
+    }
+}
+
+fn parse_match(conf: &mut CmdOptConf, nv: &NameVal<'_>) -> Result<(), OptParseError> {
+    match CmdOP::from(nv.opt.num) {
+        CmdOP::Argparse => conf.gen_conf.output = xgen::Output::Argparse,
+        CmdOP::OutDir => conf.opt_out_dir = value_to_string(nv)?,
+        CmdOP::Stem => conf.opt_stem = value_to_string(nv)?,
+        CmdOP::Generator => conf.gen_conf.generator = value_to_string(nv)?,
+        CmdOP::EnumName => conf.gen_conf.naming.enum_name = value_to_string(nv)?,
+        CmdOP::ConfName => conf.gen_conf.naming.conf_name = value_to_string(nv)?,
+        CmdOP::EnumPrefix => conf.gen_conf.naming.enum_prefix = value_to_string(nv)?,
+        CmdOP::FlagPrefix => conf.gen_conf.naming.flag_prefix = value_to_string(nv)?,
+        CmdOP::OptPrefix => conf.gen_conf.naming.opt_prefix = value_to_string(nv)?,
+        CmdOP::Type => {
+            let s = value_to_string(nv)?;
+            match s.find('=') {
+                Some(idx) => {
+                    let (name, type_s) = (&s[..idx], &s[idx + 1..]);
+                    conf.gen_conf
+                        .types
+                        .push((name.to_string(), type_s.to_string()));
+                }
+                None => {
+                    return Err(OptParseError::invalid_option_argument(
+                        nv.opt.lon,
+                        "expect <name=type>",
+                    ))
+                }
+            }
+        }
+        CmdOP::Help => conf.flag_help = true,
+        CmdOP::Version => conf.flag_version = true,
+    }
+    Ok(())
+}
+
+fn parse_cmdopts(program: &str, args: &[&str]) -> Result<CmdOptConf, OpErr> {
+    let mut conf = CmdOptConf {
+        opt_program: program.to_string(),
+        opt_out_dir: ".".to_string(),
+        ..Default::default()
+    };
+    let (opt_free, r_errs) =
+        parse_simple_gnu_style(&mut conf, &OPT_ARY, &OPT_ARY_SHO_IDX, args, parse_match);
+    r_errs?;
+    if conf.is_help() || conf.is_version() {
+        return Ok(conf);
+    }
+    match opt_free {
+        Some(free) if free.len() == 1 => conf.arg_params = free,
+        Some(free) if free.len() > 1 => {
+            let mut errs = OpErr::new();
+            errs.push(OptParseError::unexpected_argument(&free[1]));
+            return Err(errs);
+        }
+        _ => {
+            let mut errs = OpErr::new();
+            errs.push(OptParseError::missing_argument("<spec-file>"));
+            return Err(errs);
+        }
+    }
+    Ok(conf)
+}
+
+fn run(conf: &CmdOptConf) -> anyhow::Result<()> {
+    let spec_file = conf.arg_params[0].as_str();
+    let stem = if conf.opt_stem.is_empty() {
+        let name = std::path::Path::new(spec_file)
+            .file_name()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        name.trim_end_matches(".txt").to_string()
+    } else {
+        conf.opt_stem.clone()
+    };
+    let spec = xgen::parse_spec_file(spec_file, &conf.gen_conf)?;
+    xgen::write_files(
+        &spec,
+        &conf.gen_conf,
+        std::path::Path::new(&conf.opt_out_dir),
+        &stem,
+    )
+}
+
+//----------------------------------------------------------------------
+fn main() {
+    let mut env_args: Vec<String> = std::env::args().collect();
+    let program = env_args.remove(0);
+    let env_args: Vec<&str> = env_args.iter().map(std::string::String::as_str).collect();
+    //
+    let conf = match parse_cmdopts(&program, &env_args) {
+        Ok(conf) => conf,
+        Err(err) => {
+            const TRY_HELP_MSG: &str = "Try --help for help.";
+            eprintln!("{err}\n{TRY_HELP_MSG}");
+            std::process::exit(1);
+        }
+    };
+    if conf.is_help() {
+        print!("{}", help_message(&conf.opt_program));
+        std::process::exit(0);
+    }
+    if conf.is_version() {
+        println!("{}", version_message(&conf.opt_program));
+        std::process::exit(0);
+    }
+    //
+    if let Err(err) = run(&conf) {
+        eprintln!("{program}: {err:#}");
+        std::process::exit(1);
+    }
+}
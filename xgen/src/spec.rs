@@ -0,0 +1,374 @@
+//! Parsing the help text spec file.
+
+use crate::GenConf;
+
+/// Option record of the spec file
+#[rustfmt::skip]
+#[derive(Debug, Default, Clone)]
+pub struct OptStr {
+    pub num: i32,           // number
+    pub sho: String,        // short option
+    pub lon: String,        // long option
+    pub meta: String,       // option's meta
+    pub comment: String,    // option comment
+    pub type_s: String,     // type string
+    pub enum_s: String,     // enume field string
+    pub field_s: String,    // struct field string
+    pub alias: bool,        // alias of the other option, hidden from the help
+    pub hidden: bool,       // hidden from the help
+    pub expand: Vec<(String, Option<String>)>, // expanded options and values
+}
+
+impl OptStr {
+    /// long name or short name
+    pub fn lon_or_sho(&self) -> &str {
+        if !self.lon.is_empty() {
+            &self.lon
+        } else {
+            &self.sho
+        }
+    }
+    // the uppercase short name is distinguished by `-upper`. ex) `-E` is `e-upper`
+    fn base_name(&self) -> String {
+        if !self.lon.is_empty() {
+            self.lon.clone()
+        } else if self.sho.chars().all(|c| c.is_ascii_uppercase()) {
+            self.sho.to_ascii_lowercase() + "-upper"
+        } else {
+            self.sho.clone()
+        }
+    }
+    fn to_enum(&self, conf: &GenConf) -> String {
+        let r = self.base_name();
+        let v: Vec<_> = r
+            .split('-')
+            .map(|w| {
+                let mut cs: Vec<char> = w.chars().collect();
+                cs[0] = cs[0].to_ascii_uppercase();
+                let mut s = String::new();
+                for c in cs {
+                    s.push(if c == '.' { '_' } else { c });
+                }
+                s
+            })
+            .collect();
+        conf.naming.enum_prefix.clone() + &v.join("")
+    }
+    fn to_field(&self, conf: &GenConf) -> String {
+        let r = self.base_name();
+        let mut s = String::with_capacity(r.len());
+        for c in r.chars() {
+            #[rustfmt::skip]
+            let c = match c { '-' => '_', '.' => '_', _ => c, };
+            s.push(c);
+        }
+        let prefix = if self.meta.is_empty() {
+            &conf.naming.flag_prefix
+        } else {
+            &conf.naming.opt_prefix
+        };
+        let s = prefix.to_string() + &s;
+        if RUST_KEYWORDS.contains(&s.as_str()) {
+            "r#".to_string() + &s
+        } else {
+            s
+        }
+    }
+    /// field type inferred from the meta. ex) `<seconds>` is `u64`, `<file>` is `PathBuf`
+    pub fn infer_type(&self) -> &'static str {
+        let meta = self.meta.trim_start_matches('<').trim_end_matches('>');
+        if meta.is_empty() {
+            "bool"
+        } else if INTEGER_METAS.contains(&meta) {
+            "u64"
+        } else if PATH_METAS.contains(&meta) {
+            "std::path::PathBuf"
+        } else {
+            "String"
+        }
+    }
+}
+
+#[rustfmt::skip]
+const INTEGER_METAS: [&str; 12] = [
+    "bytes", "count", "depth", "integer", "lines", "milliseconds", "ms",
+    "n", "num", "number", "offset", "seconds",
+];
+
+#[rustfmt::skip]
+const PATH_METAS: [&str; 7] = [
+    "dir", "directory", "file", "file name", "filename", "path", "pathname",
+];
+
+#[rustfmt::skip]
+const RUST_KEYWORDS: [&str; 38] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn",
+    "else", "enum", "extern", "false", "fn", "for", "if", "impl", "in",
+    "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "abstract", "box",
+];
+
+/// Section of the spec file: `Options:` or `Subcommand <name>:`
+#[derive(Debug, Default, Clone)]
+pub struct Section {
+    /// subcommand name, `None` is the top level options
+    pub subcmd: Option<String>,
+    /// options in the order of the spec file
+    pub opts: Vec<OptStr>,
+    /// help text lines of this section
+    pub lines: Vec<String>,
+}
+
+impl Section {
+    /// the option of the spelled name, like `-v` or `--verbose`, except the aliases
+    pub fn find_opt(&self, name: &str) -> Option<&OptStr> {
+        self.opts.iter().find(|o| {
+            !o.alias
+                && match name.strip_prefix("--") {
+                    Some(lon) => o.lon == lon,
+                    None => !o.sho.is_empty() && name == format!("-{}", o.sho),
+                }
+        })
+    }
+}
+
+/// Parsed spec file
+#[derive(Debug, Default, Clone)]
+pub struct Spec {
+    pub sections: Vec<Section>,
+}
+
+impl Spec {
+    /// top level section
+    pub fn main_section(&self) -> Option<&Section> {
+        self.sections.iter().find(|s| s.subcmd.is_none())
+    }
+    /// subcommand names in the order of the spec file
+    pub fn subcmds(&self) -> Vec<&str> {
+        self.sections
+            .iter()
+            .filter_map(|s| s.subcmd.as_deref())
+            .collect()
+    }
+}
+
+/// Parse the spec file.
+pub fn parse_spec_file(in_file: &str, conf: &GenConf) -> anyhow::Result<Spec> {
+    let text = std::fs::read_to_string(in_file)?;
+    parse_spec(&text, conf)
+}
+
+/// Parse the spec text.
+///
+/// The non-indented lines are section headers, and the indented lines
+/// are options or the continuation of the description.
+///
+/// The option of the description `@alias <name>` is the alias of the option
+/// `<name>` in the same section, like `--colour  @alias --color`.
+/// The alias has the same `num` and is removed from the help text lines.
+/// The option of the description `@hidden <comment>` is also removed
+/// from the help text lines, like the retired options. The `<comment>`
+/// can be omitted, like `--old-style  @hidden`.
+/// The option of the description `@expand <names> <comment>` expands to
+/// the options `<names>`, like `-e  @expand -vE equivalent to -vE`.
+/// The `<names>` are the short option clusters and the long options
+/// with the optional values, separated by `,`, like `-v,--number=all`.
+/// The directive is removed from the help text lines.
+pub fn parse_spec(text: &str, conf: &GenConf) -> anyhow::Result<Spec> {
+    let mut spec = Spec::default();
+    //
+    let re_1 = regex::Regex::new(r"^ *-([^ ]), +--([^ ]+) +(<[^>]+>) +([^ ].*)$").unwrap();
+    let re_2 = regex::Regex::new(r"^ *-([^ ]), +--([^ ]+) +([^ ].*)$").unwrap();
+    let re_3 = regex::Regex::new(r"^ +--([^ ]+) +(<[^>]+>) +([^ ].*)$").unwrap();
+    let re_4 = regex::Regex::new(r"^ +--([^ ]+) +([^ ].*)$").unwrap();
+    let re_5 = regex::Regex::new(r"^ +-([^ -]) +(<[^>]+>) +([^ ].*)$").unwrap();
+    let re_6 = regex::Regex::new(r"^ +-([^ -]) +([^ ].*)$").unwrap();
+    let re_subcmd = regex::Regex::new(r"^Subcommand +([^ ]+):$").unwrap();
+    //
+    let mut v_num = 0;
+    for (line_no, line) in text.lines().enumerate() {
+        let opt = if line == "Options:" {
+            spec.sections.push(Section::default());
+            v_num = 0;
+            None
+        } else if let Some(caps) = re_subcmd.captures(line) {
+            spec.sections.push(Section {
+                subcmd: Some(caps[1].to_string()),
+                ..Section::default()
+            });
+            v_num = 0;
+            None
+        } else if let Some(caps) = re_1.captures(line) {
+            //  -C  --continue-at <offset>        Resumed transfer offset
+            Some(OptStr {
+                sho: caps[1].to_string(),
+                lon: caps[2].to_string(),
+                meta: caps[3].to_string(),
+                comment: caps[4].to_string(),
+                ..OptStr::default()
+            })
+        } else if let Some(caps) = re_2.captures(line) {
+            //  -q  --disable             Disable .curlrc
+            Some(OptStr {
+                sho: caps[1].to_string(),
+                lon: caps[2].to_string(),
+                comment: caps[3].to_string(),
+                ..OptStr::default()
+            })
+        } else if let Some(caps) = re_3.captures(line) {
+            //      --data-binary <data>  HTTP POST binary data
+            Some(OptStr {
+                lon: caps[1].to_string(),
+                meta: caps[2].to_string(),
+                comment: caps[3].to_string(),
+                ..OptStr::default()
+            })
+        } else if let Some(caps) = re_4.captures(line) {
+            //      --digest              Use HTTP Digest Authentication
+            Some(OptStr {
+                lon: caps[1].to_string(),
+                comment: caps[2].to_string(),
+                ..OptStr::default()
+            })
+        } else if let Some(caps) = re_5.captures(line) {
+            //  -n <num>                  number of lines
+            Some(OptStr {
+                sho: caps[1].to_string(),
+                meta: caps[2].to_string(),
+                comment: caps[3].to_string(),
+                ..OptStr::default()
+            })
+        } else if let Some(caps) = re_6.captures(line) {
+            //  -e                        equivalent to -vE
+            Some(OptStr {
+                sho: caps[1].to_string(),
+                comment: caps[2].to_string(),
+                ..OptStr::default()
+            })
+        } else if line.trim().is_empty() || line.starts_with(' ') {
+            // blank line or continuation of the description
+            None
+        } else {
+            anyhow::bail!("line {}: unknown spec line: {}", line_no + 1, line);
+        };
+        let sect = match spec.sections.last_mut() {
+            Some(sect) => sect,
+            None => anyhow::bail!("line {}: no section header: {}", line_no + 1, line),
+        };
+        if let Some(mut opt) = opt {
+            if let Some(comment) = directive(&opt.comment, "@hidden") {
+                //      --old-style           @hidden the old style output
+                opt.comment = comment.trim().to_string();
+                opt.hidden = true;
+                v_num += 1;
+                opt.num = v_num;
+                sect.opts.push(opt);
+                continue;
+            }
+            if let Some(name) = directive(&opt.comment, "@alias") {
+                //      --colour              @alias --color
+                let name = name.trim();
+                let primary = match sect.find_opt(name) {
+                    Some(primary) => primary,
+                    None => anyhow::bail!("line {}: unknown alias of: {}", line_no + 1, name),
+                };
+                opt.num = primary.num;
+                opt.meta = primary.meta.clone();
+                opt.alias = true;
+                sect.opts.push(opt);
+                continue;
+            }
+            if let Some(rest) = directive(&opt.comment, "@expand") {
+                //  -e                        @expand -vE equivalent to -vE
+                let names = rest.split(' ').next().unwrap_or("");
+                opt.expand = match parse_expand_names(names) {
+                    Some(v) => v,
+                    None => anyhow::bail!("line {}: invalid expand: {}", line_no + 1, names),
+                };
+                let comment = rest[names.len()..].trim_start();
+                // the help text line without the directive
+                let pos = line.find("@expand").unwrap_or(line.len());
+                let end = line.len() - comment.len();
+                opt.comment = comment.trim().to_string();
+                v_num += 1;
+                opt.num = v_num;
+                sect.opts.push(opt);
+                let help_line = line[..pos].to_string() + &line[end..];
+                sect.lines.push(help_line.trim_end().to_string());
+                continue;
+            }
+            v_num += 1;
+            opt.num = v_num;
+            sect.opts.push(opt);
+        }
+        sect.lines.push(line.to_string());
+    }
+    //
+    for sect in &spec.sections {
+        for v in sect.opts.iter() {
+            for (name, _) in v.expand.iter() {
+                if sect.find_opt(name).is_none() {
+                    anyhow::bail!("unknown expand of {}: {}", v.lon_or_sho(), name);
+                }
+            }
+        }
+    }
+    for sect in &mut spec.sections {
+        for v in sect.opts.iter_mut().filter(|o| !o.alias) {
+            let v_type = if v.meta.is_empty() { "bool" } else { "String" };
+            let v_type = conf.type_of(v.lon_or_sho()).unwrap_or(v_type);
+            //
+            v.type_s = v_type.to_string();
+            v.enum_s = v.to_enum(conf);
+            v.field_s = v.to_field(conf);
+        }
+        let primaries: Vec<OptStr> = sect.opts.iter().filter(|o| !o.alias).cloned().collect();
+        for v in sect.opts.iter_mut().filter(|o| o.alias) {
+            if let Some(primary) = primaries.iter().find(|o| o.num == v.num) {
+                v.type_s = primary.type_s.clone();
+                v.enum_s = primary.enum_s.clone();
+                v.field_s = primary.field_s.clone();
+            }
+        }
+    }
+    //
+    Ok(spec)
+}
+
+// the rest of the directive, that is followed by the white space or the end
+fn directive<'a>(comment: &'a str, name: &str) -> Option<&'a str> {
+    let rest = comment.strip_prefix(name)?;
+    if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+        Some(rest.trim_start())
+    } else {
+        None
+    }
+}
+
+// `-vE,--number=all` is `[("-v", None), ("-E", None), ("--number", Some("all"))]`
+fn parse_expand_names(names: &str) -> Option<Vec<(String, Option<String>)>> {
+    let mut v = Vec::new();
+    for name in names.split(',') {
+        if let Some(lon) = name.strip_prefix("--") {
+            let (lon, val) = match lon.split_once('=') {
+                Some((lon, val)) => (lon, Some(val.to_string())),
+                None => (lon, None),
+            };
+            if lon.is_empty() {
+                return None;
+            }
+            v.push((format!("--{}", lon), val));
+        } else if let Some(shos) = name.strip_prefix('-') {
+            if shos.is_empty() {
+                return None;
+            }
+            for c in shos.chars() {
+                v.push((format!("-{}", c), None));
+            }
+        } else {
+            return None;
+        }
+    }
+    Some(v)
+}
@@ -0,0 +1,287 @@
+const SPEC_TEXT: &str = r#"Options:
+  -a, --append              Append to target file
+      --cacert <file>       CA certificate
+                            to verify peer against
+  -e                        equivalent to -vE
+  -E, --show-ends           display $ at end of each line
+  -n <num>                  number of lines
+  -H, --help                display this help and exit
+
+Subcommand clone:
+  -b, --branch <name>       checkout <name>
+      --depth <depth>       shallow clone
+"#;
+
+#[test]
+fn parse_spec_sections() {
+    let conf = xgen::GenConf::default();
+    let spec = xgen::parse_spec(SPEC_TEXT, &conf).unwrap();
+    assert_eq!(spec.sections.len(), 2);
+    assert_eq!(spec.subcmds(), vec!["clone"]);
+    //
+    let sect = spec.main_section().unwrap();
+    let v: Vec<_> = sect
+        .opts
+        .iter()
+        .map(|o| {
+            (
+                o.num,
+                o.enum_s.as_str(),
+                o.field_s.as_str(),
+                o.type_s.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        v,
+        vec![
+            (1, "Append", "flg_append", "bool"),
+            (2, "Cacert", "opt_cacert", "String"),
+            (3, "E", "flg_e", "bool"),
+            (4, "ShowEnds", "flg_show_ends", "bool"),
+            (5, "N", "opt_n", "String"),
+            (6, "Help", "flg_help", "bool"),
+        ]
+    );
+    assert_eq!(sect.lines.len(), 9);
+    //
+    let sect = &spec.sections[1];
+    assert_eq!(sect.subcmd.as_deref(), Some("clone"));
+    assert_eq!(sect.opts[0].num, 1);
+    assert_eq!(sect.opts[1].lon, "depth");
+}
+
+#[test]
+fn parse_spec_error() {
+    let conf = xgen::GenConf::default();
+    let err = xgen::parse_spec("  -a, --append   Append\n", &conf).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1: no section header:   -a, --append   Append"
+    );
+    let err = xgen::parse_spec("Options:\nUnknown:\n", &conf).unwrap_err();
+    assert_eq!(err.to_string(), "line 2: unknown spec line: Unknown:");
+}
+
+#[test]
+fn gen_src_naming() {
+    let conf = xgen::GenConf {
+        generator: "test".to_string(),
+        naming: xgen::Naming {
+            enum_name: "Op".to_string(),
+            conf_name: "Conf".to_string(),
+            enum_prefix: "Opt".to_string(),
+            flag_prefix: "is_".to_string(),
+            opt_prefix: "".to_string(),
+        },
+        types: vec![("n".to_string(), "usize".to_string())],
+        output: xgen::Output::Tables,
+    };
+    let spec = xgen::parse_spec(SPEC_TEXT, &conf).unwrap();
+    let sect = spec.main_section().unwrap();
+    //
+    let sss = xgen::gen_src_help(sect, &conf).unwrap();
+    assert!(sss.starts_with("// WARN: This file is auto generated by test\n"));
+    assert!(sss.contains("enum Op {\n    OptAppend,\n    OptCacert,\n    OptE,\n"));
+    assert!(sss.contains(
+        "    Opt { sho: b'E', lon: \"show-ends\",     has: Arg::No,  num: Op::OptShowEnds.to(), },\n"
+    ));
+    assert!(sss.contains("const OPT_ARY_SHO_IDX: [(u8,usize);5] = [\n"));
+    assert!(sss.contains("pub struct Conf {\n"));
+    assert!(sss.contains("    pub is_append: bool,\n"));
+    assert!(sss.contains("    pub n: usize,\n"));
+    assert!(sss.contains("    fn is_help(&self) -> bool {\n        self.is_help\n"));
+    assert!(sss.contains("    fn is_version(&self) -> bool {\n        false\n"));
+    //
+    let sss = xgen::gen_src_match(sect, &conf).unwrap();
+    assert!(sss.contains("match Op::from(nv.opt.num) {\n"));
+    assert!(sss.contains("    Op::OptCacert => {\n        conf.cacert = value_to_string(nv)?;\n"));
+    assert!(sss.contains("    Op::OptN => {\n        conf.n = value_to_usize(nv)?;\n"));
+    assert!(sss.contains("    Op::OptHelp => {\n        print_help_and_exit(conf);\n"));
+    //
+    let sss = xgen::gen_src_subcmd(&spec, &conf).unwrap();
+    assert!(sss.ends_with("const SUBCMD_ARY: [&str;1] = [\"clone\",];\n"));
+}
+
+#[test]
+fn gen_src_argparse() {
+    let conf = xgen::GenConf {
+        types: vec![("n".to_string(), "usize".to_string())],
+        ..xgen::GenConf::default()
+    };
+    let spec = xgen::parse_spec(SPEC_TEXT, &conf).unwrap();
+    let sss = xgen::gen_src_argparse(spec.main_section().unwrap(), &conf).unwrap();
+    let expect = concat!(
+        "// WARN: This file is auto generated by xgen\n",
+        "flood_tide::argparse! {\n",
+        "    pub struct CmdOptConf {\n",
+        "        (flg_append, bool, b'a', \"append\", flood_tide::Arg::No, \"Append to target file\"),\n",
+        "        (opt_cacert, std::path::PathBuf, 0u8, \"cacert\", flood_tide::Arg::Yes, \"CA certificate\"),\n",
+        "        (flg_e, bool, b'e', \"\", flood_tide::Arg::No, \"equivalent to -vE\"),\n",
+        "        (flg_show_ends, bool, b'E', \"show-ends\", flood_tide::Arg::No, \"display $ at end of each line\"),\n",
+        "        (opt_n, usize, b'n', \"\", flood_tide::Arg::Yes, \"number of lines\"),\n",
+        "        (flg_help, bool, b'H', \"help\", flood_tide::Arg::No, \"display this help and exit\", @help),\n",
+        "    }\n",
+        "}\n",
+    );
+    assert_eq!(sss, expect);
+    //
+    let spec = xgen::parse_spec(SPEC_TEXT, &xgen::GenConf::default()).unwrap();
+    let sect = &spec.sections[1];
+    assert_eq!(sect.opts[1].infer_type(), "u64");
+    assert_eq!(sect.opts[0].infer_type(), "String");
+}
+
+#[test]
+fn gen_src_alias() {
+    let text = concat!(
+        "Options:\n",
+        "      --color <when>        colorize the output\n",
+        "      --colour <when>       @alias --color\n",
+        "  -v, --verbose             verbose mode\n",
+        "  -V                        @alias -v\n",
+    );
+    let conf = xgen::GenConf {
+        generator: "test".to_string(),
+        ..xgen::GenConf::default()
+    };
+    let spec = xgen::parse_spec(text, &conf).unwrap();
+    let sect = spec.main_section().unwrap();
+    let v: Vec<_> = sect
+        .opts
+        .iter()
+        .map(|o| (o.num, o.enum_s.as_str(), o.meta.as_str(), o.alias))
+        .collect();
+    assert_eq!(
+        v,
+        vec![
+            (1, "Color", "<when>", false),
+            (1, "Color", "<when>", true),
+            (2, "Verbose", "", false),
+            (2, "Verbose", "", true),
+        ]
+    );
+    // the help shows only the primary name
+    assert_eq!(sect.lines.len(), 3);
+    //
+    let sss = xgen::gen_src_help(sect, &conf).unwrap();
+    assert!(sss.contains("enum CmdOP {\n    Color,\n    Verbose,\n}\n"));
+    assert!(sss.contains(
+        "    Opt { sho: 0u8,  lon: \"colour\",        has: Arg::Yes, num: CmdOP::Color.to(), },\n"
+    ));
+    assert!(sss.contains("    Opt { sho: b'V', lon: \"\",              has: Arg::No,  num: CmdOP::Verbose.to(), },\n"));
+    assert!(sss.contains("    pub opt_color: String,\n    pub flg_verbose: bool,\n    //\n"));
+    assert!(!sss.contains("@alias"));
+    //
+    let sss = xgen::gen_src_match(sect, &conf).unwrap();
+    assert_eq!(sss.matches("CmdOP::Color =>").count(), 1);
+    //
+    let err = xgen::gen_src_argparse(sect, &conf).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "alias is not supported by argparse!: colour"
+    );
+    //
+    let err = xgen::parse_spec("Options:\n  -V   @alias -v\n", &conf).unwrap_err();
+    assert_eq!(err.to_string(), "line 2: unknown alias of: -v");
+    let err = xgen::parse_spec("Options:\n  -V   @alias\n", &conf).unwrap_err();
+    assert_eq!(err.to_string(), "line 2: unknown alias of: ");
+}
+
+#[test]
+fn gen_src_hidden() {
+    let text = concat!(
+        "Options:\n",
+        "      --new-style           the new style output\n",
+        "      --old-style           @hidden the old style output\n",
+        "      --older-style         @hidden\n",
+        "      --hiddenness          @hiddenness is not the directive\n",
+    );
+    let conf = xgen::GenConf::default();
+    let spec = xgen::parse_spec(text, &conf).unwrap();
+    let sect = spec.main_section().unwrap();
+    let v: Vec<_> = sect
+        .opts
+        .iter()
+        .map(|o| (o.num, o.lon.as_str(), o.comment.as_str(), o.hidden))
+        .collect();
+    assert_eq!(
+        v,
+        vec![
+            (1, "new-style", "the new style output", false),
+            (2, "old-style", "the old style output", true),
+            (3, "older-style", "", true),
+            (4, "hiddenness", "@hiddenness is not the directive", false),
+        ]
+    );
+    assert_eq!(sect.lines.len(), 3);
+    //
+    let sss = xgen::gen_src_help(sect, &conf).unwrap();
+    assert!(sss.contains(
+        "enum CmdOP {\n    NewStyle,\n    OldStyle,\n    OlderStyle,\n    Hiddenness,\n}\n"
+    ));
+    assert!(!sss.contains("@hidden ") && !sss.contains("@hidden\n"));
+}
+
+#[test]
+fn gen_src_expand() {
+    let text = concat!(
+        "Options:\n",
+        "  -A, --show-all            @expand -vET equivalent to -vET\n",
+        "  -e                        @expand -vE equivalent to -vE\n",
+        "  -E, --show-ends           display $ at end of each line\n",
+        "      --number <when>       number the lines\n",
+        "  -n                        @expand --number=all\n",
+        "  -T, --show-tabs           display TAB characters as ^I\n",
+        "  -v, --show-nonprinting    use ^ and M- notation\n",
+    );
+    let conf = xgen::GenConf::default();
+    let spec = xgen::parse_spec(text, &conf).unwrap();
+    let sect = spec.main_section().unwrap();
+    let v: Vec<_> = sect
+        .opts
+        .iter()
+        .map(|o| (o.num, o.comment.as_str(), o.expand.len()))
+        .collect();
+    assert_eq!(
+        v,
+        vec![
+            (1, "equivalent to -vET", 3),
+            (2, "equivalent to -vE", 2),
+            (3, "display $ at end of each line", 0),
+            (4, "number the lines", 0),
+            (5, "", 1),
+            (6, "display TAB characters as ^I", 0),
+            (7, "use ^ and M- notation", 0),
+        ]
+    );
+    assert_eq!(
+        sect.lines[1],
+        "  -A, --show-all            equivalent to -vET"
+    );
+    assert_eq!(sect.lines[5], "  -n");
+    //
+    let sss = xgen::gen_src_help(sect, &conf).unwrap();
+    assert!(!sss.contains("@expand"));
+    let expect = concat!(
+        "#[rustfmt::skip]\n",
+        "const EXPAND_ARY: [Expand;3] = [\n",
+        "    (CmdOP::ShowAll.to(), &[(CmdOP::ShowNonprinting.to(), None),",
+        "(CmdOP::ShowEnds.to(), None),(CmdOP::ShowTabs.to(), None),]),\n",
+        "    (CmdOP::E.to(), &[(CmdOP::ShowNonprinting.to(), None),(CmdOP::ShowEnds.to(), None),]),\n",
+        "    (CmdOP::N.to(), &[(CmdOP::Number.to(), Some(\"all\")),]),\n",
+        "];\n",
+    );
+    assert!(sss.contains(expect));
+    //
+    let err = xgen::gen_src_argparse(sect, &conf).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expand is not supported by argparse!: show-all"
+    );
+    //
+    let err = xgen::parse_spec("Options:\n  -e   @expand -vX\n", &conf).unwrap_err();
+    assert_eq!(err.to_string(), "unknown expand of e: -v");
+    let err = xgen::parse_spec("Options:\n  -e   @expand vE\n", &conf).unwrap_err();
+    assert_eq!(err.to_string(), "line 2: invalid expand: vE");
+}
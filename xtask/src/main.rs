@@ -2,10 +2,6 @@
 // ref)
 //   https://github.com/matklad/cargo-xtask
 //
-use anyhow::Context;
-use std::io::Read;
-use std::io::Write;
-
 mod gen_features_combination;
 mod gen_src_example_curl_cmd;
 
@@ -58,24 +54,7 @@ fn all_gen() -> anyhow::Result<()> {
     Ok(())
 }
 
-pub fn update_file(sss: &str, file_path: &str) -> anyhow::Result<()> {
-    let contents = {
-        let mut contents = String::new();
-        if let Ok(mut file) = std::fs::File::open(file_path) {
-            file.read_to_string(&mut contents)
-                .with_context(|| format!("could not read file `{file_path}`"))?;
-        }
-        contents
-    };
-    if contents != *sss {
-        println!("update: {file_path}");
-        let mut file = std::fs::File::create(file_path)
-            .with_context(|| format!("could not create file `{file_path}`"))?;
-        write!(file, "{sss}").with_context(|| format!("could not write file `{file_path}`"))?;
-    }
-    //
-    Ok(())
-}
+pub use xgen::update_file;
 
 fn do_x() -> anyhow::Result<()> {
     Ok(())